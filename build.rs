@@ -1,10 +1,47 @@
+use std::fs;
+
+use build_probe_mpi::Library;
+
+/// Read a version number `#define`d as `name` in the `mpi.h` header of the library.
+fn mpi_define(lib: &Library, name: &str) -> Option<u32> {
+    lib.include_paths.iter().find_map(|dir| {
+        let header = fs::read_to_string(dir.join("mpi.h")).ok()?;
+        header.lines().find_map(|line| {
+            let value = line
+                .trim()
+                .strip_prefix("#define")?
+                .trim()
+                .strip_prefix(name)?;
+            value.trim().parse().ok()
+        })
+    })
+}
+
+/// Read `MPI_VERSION` and `MPI_SUBVERSION` from the `mpi.h` header of the library.
+fn mpi_version(lib: &Library) -> Option<(u32, u32)> {
+    Some((
+        mpi_define(lib, "MPI_VERSION")?,
+        mpi_define(lib, "MPI_SUBVERSION").unwrap_or(0),
+    ))
+}
+
 fn main() {
-    let is_msmpi = match build_probe_mpi::probe() {
-        Ok(lib) => lib.version == "MS-MPI",
-        _ => false,
+    let (is_msmpi, version) = match build_probe_mpi::probe() {
+        Ok(lib) => (lib.version == "MS-MPI", mpi_version(&lib)),
+        _ => (false, None),
     };
 
+    // Declare the custom cfgs so that `unexpected_cfgs` does not fire on them
+    println!("cargo:rustc-check-cfg=cfg(msmpi)");
+    println!("cargo:rustc-check-cfg=cfg(mpi4)");
+    println!("cargo:rustc-check-cfg=cfg(mpi4_1)");
     if is_msmpi {
         println!("cargo:rustc-cfg=msmpi");
     }
+    if version.is_some_and(|version| version >= (4, 0)) {
+        println!("cargo:rustc-cfg=mpi4");
+    }
+    if version.is_some_and(|version| version >= (4, 1)) {
+        println!("cargo:rustc-cfg=mpi4_1");
+    }
 }
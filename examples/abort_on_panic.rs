@@ -0,0 +1,87 @@
+#![deny(warnings)]
+
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+
+use mpi::environment::defer_abort_on_panic;
+use mpi::request::{self, WaitGuard};
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    // A panic on any process now aborts all processes with error code 3
+    universe.set_abort_on_panic(Some(3));
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+
+    // Requests held in guards are completed before aborting if the closure panics
+    let mut value = -1;
+    let received = defer_abort_on_panic(|| {
+        request::scope(|scope| {
+            let _receive = WaitGuard::from(
+                world
+                    .process_at_rank(previous_rank)
+                    .immediate_receive_into(scope, &mut value),
+            );
+            let _send = WaitGuard::from(
+                world
+                    .process_at_rank(next_rank)
+                    .immediate_send(scope, &rank),
+            );
+        });
+        value
+    });
+    assert_eq!(received, previous_rank);
+
+    // Plain requests are waited for while unwinding a deferred panic.  The nested call resumes
+    // the panic, which is caught here before it reaches the outer call that would abort.
+    let mut value = -1;
+    defer_abort_on_panic(|| {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            defer_abort_on_panic(|| {
+                request::scope(|scope| {
+                    let _receive = world
+                        .process_at_rank(previous_rank)
+                        .immediate_receive_into(scope, &mut value);
+                    let _send = world
+                        .process_at_rank(next_rank)
+                        .immediate_send(scope, &rank);
+                    panic!("Process {} panicked on purpose", rank);
+                })
+            })
+        }));
+        assert!(result.is_err());
+    });
+    assert_eq!(value, previous_rank);
+
+    // Without abort on panic, panics are resumed
+    universe.set_abort_on_panic(None);
+    let result = panic::catch_unwind(|| {
+        defer_abort_on_panic(|| {
+            panic!("Process {} panicked on purpose", rank);
+        })
+    });
+    assert!(result.is_err());
+    universe.set_abort_on_panic(Some(3));
+
+    world.barrier();
+
+    // Actually aborting ends the job with a failure, so it only happens on request
+    if env::var_os("RSMPI_EXAMPLE_ABORT").is_some() {
+        defer_abort_on_panic(|| {
+            request::scope(|scope| {
+                let _send = world
+                    .process_at_rank(next_rank)
+                    .immediate_send(scope, &rank);
+                let _receive = world
+                    .process_at_rank(previous_rank)
+                    .immediate_receive_into(scope, &mut value);
+                panic!("Process {} aborts all processes", rank);
+            })
+        });
+        unreachable!();
+    }
+}
@@ -0,0 +1,39 @@
+#![deny(warnings)]
+
+use mpi::traits::*;
+use mpi::Count;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let rank = world.rank();
+    let size = world.size();
+
+    let gathered = world.all_gather_vec(&[rank, rank * 2]);
+    let expected: Vec<_> = (0..size).flat_map(|r| vec![r, r * 2]).collect();
+    assert_eq!(gathered, expected);
+
+    // Rank `r` contributes `r` elements.
+    let msg: Vec<_> = (0..rank).collect();
+    let (gathered, counts) = world.all_gather_varcount_vec(&msg[..]);
+    assert_eq!(counts, (0..size).collect::<Vec<Count>>());
+    let expected: Vec<_> = (0..size).flat_map(|r| 0..r).collect();
+    assert_eq!(gathered, expected);
+    println!("Process {} got message {:?}", rank, gathered);
+
+    let msg: Vec<_> = (0..size).map(|r| rank * size + r).collect();
+    let received = world.all_to_all_vec(&msg[..]);
+    let expected: Vec<_> = (0..size).map(|r| r * size + rank).collect();
+    assert_eq!(received, expected);
+
+    // Rank `r` sends `r + 1` copies of its rank to every process.
+    let sendcounts: Vec<Count> = vec![rank + 1; size as usize];
+    let msg = vec![rank; ((rank + 1) * size) as usize];
+    let (received, recvcounts) = world.all_to_all_varcount_vec(&msg[..], &sendcounts[..]);
+    assert_eq!(recvcounts, (1..=size).collect::<Vec<Count>>());
+    let expected: Vec<_> = (0..size)
+        .flat_map(|r| std::iter::repeat(r).take((r + 1) as usize))
+        .collect();
+    assert_eq!(received, expected);
+}
@@ -0,0 +1,64 @@
+#![deny(warnings)]
+
+use std::alloc::Layout;
+
+use mpi::memory::{MpiAllocator, MpiBox, MpiVec};
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+
+    let layout = Layout::array::<f64>(16).unwrap();
+    let memory = MpiAllocator.allocate(layout).unwrap();
+    assert_eq!(memory.as_ptr() as usize % layout.align(), 0);
+    unsafe {
+        MpiAllocator.deallocate(memory, layout);
+    }
+
+    let mut values = MpiVec::new();
+    assert_eq!(values.capacity(), 0);
+    values.extend((0..100).map(|i| rank * 100 + i));
+    values.push(-1);
+    assert_eq!(values.len(), 101);
+    assert_eq!(values.pop(), Some(-1));
+
+    let mut received = MpiVec::with_capacity(100).unwrap();
+    received.extend_from_slice(&[0; 100]);
+    mpi::request::scope(|scope| {
+        let request = world
+            .process_at_rank(previous_rank)
+            .immediate_receive_into(scope, &mut received);
+        world.process_at_rank(next_rank).send(&values);
+        request.wait();
+    });
+    assert!(received
+        .iter()
+        .enumerate()
+        .all(|(i, &value)| value == previous_rank * 100 + i as i32));
+
+    let mut root_value = MpiBox::new(if rank == 0 { 42u64 } else { 0 }).unwrap();
+    world.process_at_rank(0).broadcast_into(&mut *root_value);
+    assert_eq!(*root_value, 42);
+
+    let mut sums = MpiBox::from_slice(&[rank; 4]).unwrap();
+    world.all_reduce_into(
+        &values[..4],
+        &mut sums,
+        mpi::collective::SystemOperation::sum(),
+    );
+    assert_eq!(sums[0], (0..size).map(|r| r * 100).sum::<i32>());
+
+    values.clear();
+    assert!(values.is_empty());
+
+    let mut window_memory = MpiVec::with_capacity(8).unwrap();
+    window_memory.extend_from_slice(&[rank; 8]);
+    let window = world.create_slice_window(&mut window_memory);
+    assert_eq!(window.window_slice, [rank; 8]);
+    drop(window);
+}
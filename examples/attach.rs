@@ -0,0 +1,46 @@
+#![deny(warnings)]
+
+use std::ptr;
+use std::sync::{Arc, Mutex};
+
+use mpi::traits::*;
+
+fn main() {
+    // MPI is initialized by a host program, e.g. written in C
+    unsafe {
+        mpi::ffi::MPI_Init(ptr::null_mut(), ptr::null_mut());
+    }
+    assert!(mpi::initialize().is_none());
+
+    let universe = mpi::attach().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let mut sum = 0;
+    world.all_reduce_into(&1, &mut sum, mpi::collective::SystemOperation::sum());
+    assert_eq!(sum, world.size());
+
+    let finalized = Arc::new(Mutex::new(Vec::new()));
+    for name in ["datatypes", "windows"] {
+        let finalized = finalized.clone();
+        mpi::at_finalize(move || {
+            // MPI can still be used by the hooks
+            let universe = mpi::attach().unwrap();
+            assert_eq!(universe.world().rank(), rank);
+            finalized.lock().unwrap().push(name);
+        });
+    }
+
+    // Does not finalize MPI
+    drop(universe);
+    let universe = mpi::attach().unwrap();
+    universe.world().barrier();
+    drop(universe);
+    assert!(finalized.lock().unwrap().is_empty());
+
+    // The host program finalizes MPI
+    unsafe {
+        mpi::ffi::MPI_Finalize();
+    }
+    assert_eq!(*finalized.lock().unwrap(), ["windows", "datatypes"]);
+    assert!(mpi::attach().is_none());
+}
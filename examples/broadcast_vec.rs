@@ -0,0 +1,39 @@
+#![deny(warnings)]
+
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    let mut v = if world.rank() == root_rank {
+        (0..world.size() * 3).map(|x| x as f64).collect()
+    } else {
+        vec![-1.0]
+    };
+    root_process.broadcast_vec(&mut v);
+    println!("Rank {} received vector: {:?}.", world.rank(), v);
+    assert_eq!(
+        v,
+        (0..world.size() * 3).map(|x| x as f64).collect::<Vec<_>>()
+    );
+
+    let mut s = if world.rank() == root_rank {
+        String::from("Grüße aus dem Wurzelprozess")
+    } else {
+        String::new()
+    };
+    root_process.broadcast_string(&mut s);
+    println!("Rank {} received string: {}.", world.rank(), s);
+    assert_eq!(s, "Grüße aus dem Wurzelprozess");
+
+    let mut empty: Vec<u8> = if world.rank() == root_rank {
+        Vec::new()
+    } else {
+        vec![1, 2, 3]
+    };
+    root_process.broadcast_vec(&mut empty);
+    assert!(empty.is_empty());
+}
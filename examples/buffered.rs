@@ -1,7 +1,9 @@
 #![deny(warnings)]
 
+use mpi::point_to_point::BufferedSendPlan;
 use mpi::request::WaitGuard;
 use mpi::traits::*;
+use mpi::MpiError;
 
 const BUFFER_SIZE: usize = 10 * 1024 * 1024;
 
@@ -29,4 +31,44 @@ fn main() {
         world.this_process().buffered_send(&x[..]);
     });
     assert_eq!(x, y);
+
+    // Attach a buffer that fits exactly one message like `x`.
+    let plan = BufferedSendPlan::new().message(&world, &x[..]);
+    assert!(plan.size() > x.len() * std::mem::size_of::<f32>());
+    universe.set_buffer_size(plan.size());
+
+    let z = vec![std::f32::consts::E; 2 * x.len()];
+    match world.this_process().try_buffered_send(&z[..]) {
+        Err(MpiError::BufferedSendOverflow(size)) => assert!(size > plan.size()),
+        result => panic!("unexpected result of an oversized send: {:?}", result),
+    }
+
+    let mut y = vec![0.0; 1024];
+    mpi::request::scope(|scope| {
+        let _rreq = WaitGuard::from(
+            world
+                .any_process()
+                .immediate_receive_into(scope, &mut y[..]),
+        );
+        world.this_process().try_buffered_send(&x[..]).unwrap();
+    });
+    assert_eq!(x, y);
+
+    #[cfg(mpi4_1)]
+    {
+        use mpi::point_to_point::CommunicatorBuffer;
+
+        let comm = world.duplicate();
+        let buffer = CommunicatorBuffer::attach(&comm, plan.size()).unwrap();
+        assert_eq!(buffer.size(), plan.size());
+
+        let mut y = vec![0.0; 1024];
+        mpi::request::scope(|scope| {
+            let _rreq =
+                WaitGuard::from(comm.any_process().immediate_receive_into(scope, &mut y[..]));
+            comm.this_process().buffered_send(&x[..]);
+        });
+        assert_eq!(x, y);
+        drop(buffer);
+    }
 }
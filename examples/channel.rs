@@ -0,0 +1,64 @@
+#![deny(warnings)]
+
+use mpi::channel::Channels;
+use mpi::traits::*;
+use mpi::MpiError;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+
+    let channels = Channels::new(&world).unwrap();
+    let (counts, count_receiver) = channels.channel::<u64>(0).unwrap();
+    let (positions, position_receiver) = channels.channel::<f64>(1).unwrap();
+
+    assert!(matches!(
+        channels.channel::<i32>(1),
+        Err(MpiError::TagInUse(1))
+    ));
+    assert!(matches!(
+        channels.channel::<i32>(-1),
+        Err(MpiError::TagOutOfRange(-1, _))
+    ));
+
+    let position = rank as f64;
+    mpi::request::scope(|scope| {
+        // Sent first, but received last
+        let position_request = positions.immediate_send(scope, next_rank, &position);
+        counts.send(next_rank, &(rank as u64));
+
+        let (count, status) = count_receiver.receive();
+        assert_eq!(count, previous_rank as u64);
+        assert_eq!(status.source_rank(), previous_rank);
+        assert_eq!(status.tag(), count_receiver.tag());
+
+        let (received, _) = position_receiver.receive_from(previous_rank);
+        assert_eq!(received, previous_rank as f64);
+        position_request.wait();
+    });
+
+    world.barrier();
+    assert!(count_receiver.try_receive().is_none());
+
+    let mut received = 0;
+    mpi::request::scope(|scope| {
+        let request = count_receiver.immediate_receive_into(scope, &mut received);
+        counts.send(next_rank, &42);
+        request.wait();
+    });
+    assert_eq!(received, 42);
+
+    // The tag of a channel is released once both of its halves have been dropped.
+    drop(positions);
+    assert!(matches!(
+        channels.channel::<i32>(1),
+        Err(MpiError::TagInUse(1))
+    ));
+    drop(position_receiver);
+    assert!(channels.channel::<i32>(1).is_ok());
+}
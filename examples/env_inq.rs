@@ -1,12 +1,31 @@
 #![deny(warnings)]
 
+use mpi::environment::IoProcess;
+use mpi::traits::*;
+
 fn main() {
     let (version, subversion) = mpi::environment::version();
     println!("This is MPI-{}.{}.", version, subversion);
     println!("{}", mpi::environment::library_version().unwrap());
-    let _universe = mpi::initialize().unwrap();
+    let universe = mpi::initialize().unwrap();
     println!("{}", mpi::environment::processor_name().unwrap());
 
+    assert!(universe.tag_ub() >= 32767);
+    if let Some(host) = universe.host() {
+        assert!(host < universe.world().size());
+    }
+    match universe.io_process() {
+        IoProcess::Rank(rank) => assert!(rank < universe.world().size()),
+        IoProcess::Any | IoProcess::None => {}
+    }
+    println!(
+        "Tag upper bound {}, host {:?}, I/O {:?}, global clock {}",
+        universe.tag_ub(),
+        universe.host(),
+        universe.io_process(),
+        universe.wtime_is_global()
+    );
+
     #[cfg(not(msmpi))]
     assert!(
         version >= 3,
@@ -0,0 +1,125 @@
+#![deny(warnings)]
+
+#[cfg(feature = "user-operations")]
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use mpi::error_handling::{self, ErrorHandler};
+use mpi::topology::SimpleCommunicator;
+use mpi::traits::*;
+use mpi::MpiError;
+
+#[cfg(feature = "user-operations")]
+static LAST_ERROR: AtomicI32 = AtomicI32::new(0);
+
+#[cfg(feature = "user-operations")]
+fn test_error_handlers(world: &SimpleCommunicator, comm: &SimpleCommunicator) {
+    let rank = comm.rank();
+    let next_rank = (rank + 1) % comm.size();
+
+    // Record errors instead of aborting, the failing call still returns the error code
+    comm.set_name("logged");
+    let handler = ErrorHandler::communicator(|comm, code| {
+        println!(
+            "Process {} failed on communicator {:?} with error code {}",
+            comm.rank(),
+            comm.get_name(),
+            code
+        );
+        LAST_ERROR.store(code, Ordering::SeqCst);
+    });
+    comm.set_error_handler(&handler);
+    drop(handler);
+    let duplicate = comm.duplicate();
+
+    let code = duplicate
+        .process_at_rank(next_rank)
+        .try_send_with_tag(&rank, -5)
+        .unwrap_err()
+        .class()
+        .unwrap();
+    assert_ne!(LAST_ERROR.load(Ordering::SeqCst), 0);
+    assert_ne!(code, 0);
+
+    // Errors of a library built on MPI
+    let class = error_handling::add_error_class();
+    let code = error_handling::add_error_code(class);
+    error_handling::add_error_string(code, "The domain is inconsistent").unwrap();
+    assert!(error_handling::add_error_string(code, "in\0valid").is_err());
+    duplicate.call_error_handler(code);
+    assert_eq!(LAST_ERROR.load(Ordering::SeqCst), code);
+    match MpiError::from_code("check_domain", code) {
+        MpiError::Call {
+            class: error_class,
+            message,
+            ..
+        } => {
+            assert_eq!(error_class, class);
+            assert_eq!(message, "The domain is inconsistent");
+        }
+        error => panic!("Unexpected error {:?}", error),
+    }
+
+    let installed = duplicate.get_error_handler();
+    world.set_error_handler(&installed);
+    world.set_error_handler(&ErrorHandler::errors_are_fatal());
+}
+
+#[cfg(not(feature = "user-operations"))]
+fn test_error_handlers(_: &SimpleCommunicator, _: &SimpleCommunicator) {}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let comm = world.duplicate();
+    comm.set_error_handler(&ErrorHandler::errors_return());
+
+    let rank = comm.rank();
+    let size = comm.size();
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+
+    // Negative tags other than `MPI_ANY_TAG` are invalid
+    match comm.process_at_rank(next_rank).try_send_with_tag(&rank, -5) {
+        Err(MpiError::Call { call, class, .. }) => {
+            assert_eq!(call, "MPI_Send");
+            assert_ne!(class, 0);
+        }
+        result => panic!("Unexpected result {:?}", result),
+    }
+    let mut value = 0;
+    let error = comm
+        .process_at_rank(previous_rank)
+        .try_receive_into_with_tag(&mut value, -5)
+        .unwrap_err();
+    assert!(error.class().is_some());
+    println!("Process {} got error: {}", rank, error);
+
+    comm.try_barrier().unwrap();
+
+    let mut sum = 0;
+    comm.try_all_reduce_into(&rank, &mut sum, mpi::collective::SystemOperation::sum())
+        .unwrap();
+    assert_eq!(sum, size * (size - 1) / 2);
+
+    let mut root_value = if rank == 0 { 42 } else { 0 };
+    comm.process_at_rank(0)
+        .try_broadcast_into(&mut root_value)
+        .unwrap();
+    assert_eq!(root_value, 42);
+
+    mpi::request::scope(|scope| {
+        let request = comm
+            .process_at_rank(previous_rank)
+            .immediate_receive_into(scope, &mut value);
+        comm.process_at_rank(next_rank).try_send(&rank).unwrap();
+        let status = request.try_wait().unwrap();
+        assert_eq!(status.source_rank(), previous_rank);
+    });
+    assert_eq!(value, previous_rank);
+
+    test_error_handlers(&world, &comm);
+
+    let file_handler = error_handling::default_file_error_handler();
+    error_handling::set_default_file_error_handler(&ErrorHandler::errors_return());
+    error_handling::set_default_file_error_handler(&file_handler);
+}
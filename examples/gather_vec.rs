@@ -0,0 +1,37 @@
+#![deny(warnings)]
+
+use mpi::traits::*;
+use mpi::Count;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    let rank = world.rank();
+    let size = world.size();
+
+    let i = 2_u64.pow(rank as u32 + 1);
+    let gathered = root_process.gather_vec(&[i]);
+    if rank == root_rank {
+        let gathered = gathered.expect("root must receive the gathered sequence");
+        println!("Root gathered sequence: {:?}.", gathered);
+        let expected: Vec<_> = (0..size).map(|r| 2_u64.pow(r as u32 + 1)).collect();
+        assert_eq!(gathered, expected);
+    } else {
+        assert!(gathered.is_none());
+    }
+
+    // Rank `r` contributes `r` elements.
+    let msg: Vec<_> = (0..rank).collect();
+    let gathered = root_process.gather_varcount_vec(&msg[..]);
+    if rank == root_rank {
+        let (gathered, counts) = gathered.expect("root must receive the gathered elements");
+        assert_eq!(counts, (0..size).collect::<Vec<Count>>());
+        let expected: Vec<_> = (0..size).flat_map(|r| 0..r).collect();
+        assert_eq!(gathered, expected);
+    } else {
+        assert!(gathered.is_none());
+    }
+}
@@ -0,0 +1,118 @@
+#![deny(warnings)]
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use mpi::point_to_point::Status;
+use mpi::request::{self, GeneralizedOperation, StaticScope};
+use mpi::traits::*;
+use mpi::{Count, Threading};
+
+const STAGED_BYTES: usize = 4096;
+const STAGING: i32 = -1;
+
+/// Stands in for staging a file, reports the number of bytes staged
+struct Staging {
+    staged: Arc<AtomicUsize>,
+}
+
+impl GeneralizedOperation for Staging {
+    fn query(&mut self, status: &mut Status) {
+        let staged = self.staged.load(Ordering::SeqCst) as Count;
+        status.set_elements(u8::equivalent_datatype(), staged);
+    }
+}
+
+/// Records whether it has been cancelled
+struct Cancellable<'a> {
+    cancelled: &'a Cell<bool>,
+}
+
+impl<'a> GeneralizedOperation for Cancellable<'a> {
+    fn cancel(&mut self, complete: bool) {
+        assert!(!complete);
+        self.cancelled.set(true);
+    }
+}
+
+fn main() {
+    let (universe, threading) = mpi::initialize_with_threading(Threading::Multiple).unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+
+    let staged = Arc::new(AtomicUsize::new(0));
+    let staging = STAGING;
+    let mut received = STAGING;
+
+    thread::scope(|threads| {
+        request::multiple_scope(3, |scope, coll| {
+            coll.add(
+                world
+                    .process_at_rank(next_rank)
+                    .immediate_send(scope, &rank),
+            );
+            coll.add(
+                world
+                    .process_at_rank(previous_rank)
+                    .immediate_receive_into(scope, &mut received),
+            );
+
+            let operation = Staging {
+                staged: staged.clone(),
+            };
+            let (request, completer) = request::start_generalized(scope, &staging, operation);
+            coll.add(request);
+
+            let staged = staged.clone();
+            let stage = move || {
+                staged.store(STAGED_BYTES, Ordering::SeqCst);
+                completer.complete();
+            };
+            if threading == Threading::Multiple {
+                threads.spawn(stage);
+            } else {
+                stage();
+            }
+
+            let mut staging_completed = false;
+            while coll.incomplete() > 0 {
+                let (_, status, &data) = coll.wait_any().unwrap();
+                if data == STAGING {
+                    assert_eq!(
+                        status.count(u8::equivalent_datatype()),
+                        STAGED_BYTES as Count
+                    );
+                    staging_completed = true;
+                }
+            }
+            assert!(staging_completed);
+        });
+    });
+    assert_eq!(received, previous_rank);
+
+    let cancelled = Cell::new(false);
+    request::scope(|scope| {
+        let operation = Cancellable {
+            cancelled: &cancelled,
+        };
+        let (request, completer) = request::start_generalized(scope, &(), operation);
+        request.cancel();
+        completer.complete();
+        request.wait();
+    });
+    assert!(cancelled.get());
+
+    let (request, completer) = request::start_generalized(StaticScope, &(), Staging { staged });
+    drop(completer);
+    let status = request.wait();
+    assert_eq!(
+        status.count(u8::equivalent_datatype()),
+        STAGED_BYTES as Count
+    );
+}
@@ -86,4 +86,12 @@ fn main() {
 
         let _sreq = CancelGuard::from(world.this_process().immediate_receive_into(scope, &mut y));
     });
+
+    mpi::request::scope(|scope| {
+        let rreq = world
+            .this_process()
+            .immediate_receive_into_with_tag(scope, &mut y, 27);
+        rreq.cancel();
+        assert!(rreq.wait().is_cancelled());
+    });
 }
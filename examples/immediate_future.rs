@@ -0,0 +1,78 @@
+#![deny(warnings)]
+
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake};
+use std::thread::{self, Thread};
+
+use mpi::collective::SystemOperation;
+use mpi::request;
+use mpi::traits::*;
+use mpi::Threading;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// A minimal executor, if `progress_inline` is set, it drives MPI progress itself.
+fn block_on<F: Future>(future: F, progress_inline: bool) -> F::Output {
+    let mut future = Box::pin(future);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        if progress_inline {
+            request::progress();
+        } else {
+            thread::park();
+        }
+    }
+}
+
+fn main() {
+    let (universe, threading) = mpi::initialize_with_threading(Threading::Multiple).unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+
+    let exchange = async {
+        let recv = world
+            .process_at_rank(previous_rank)
+            .immediate_receive_into_owned(vec![0; 3]);
+        let send = world
+            .process_at_rank(next_rank)
+            .immediate_send_owned(vec![rank; 3]);
+        let (msg, status) = recv.await;
+        send.await;
+        let ((_, sum), _) = world
+            .immediate_all_reduce_into_owned(rank, 0, SystemOperation::sum())
+            .await;
+        (msg, status, sum)
+    };
+
+    let (msg, status, sum) = if threading == Threading::Multiple {
+        let stop = AtomicBool::new(false);
+        thread::scope(|s| {
+            s.spawn(|| request::drive_progress(&stop));
+            let output = block_on(exchange, false);
+            stop.store(true, Ordering::Release);
+            output
+        })
+    } else {
+        block_on(exchange, true)
+    };
+
+    assert_eq!(msg, vec![previous_rank; 3]);
+    assert_eq!(status.source_rank(), previous_rank);
+    assert_eq!(sum, size * (size - 1) / 2);
+}
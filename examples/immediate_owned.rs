@@ -0,0 +1,63 @@
+#![deny(warnings)]
+
+use mpi::collective::SystemOperation;
+use mpi::request::OwnedRequest;
+use mpi::topology::Rank;
+use mpi::traits::*;
+
+fn start_all_reduce<C: Communicator>(comm: &C) -> OwnedRequest<(Rank, Vec<Rank>)> {
+    comm.immediate_all_reduce_into_owned(comm.rank(), vec![0], SystemOperation::sum())
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let ((_, sum), _) = start_all_reduce(&world).wait();
+    assert_eq!(sum, vec![size * (size - 1) / 2]);
+
+    let root_process = world.process_at_rank(0);
+    let buf = if rank == 0 {
+        vec![1, 2, 3].into_boxed_slice()
+    } else {
+        vec![0; 3].into_boxed_slice()
+    };
+    let mut req = root_process.immediate_broadcast_into_owned(buf);
+    let buf = loop {
+        match req.test() {
+            Ok((buf, _)) => break buf,
+            Err(r) => req = r,
+        }
+    };
+    assert_eq!(&buf[..], &[1, 2, 3]);
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+    let recv = world
+        .process_at_rank(previous_rank)
+        .immediate_receive_into_owned(vec![0; 2]);
+    let send = world
+        .process_at_rank(next_rank)
+        .immediate_send_owned(vec![rank, 2 * rank]);
+    let (msg, status) = recv.wait();
+    send.wait_without_status();
+    assert_eq!(msg, vec![previous_rank, 2 * previous_rank]);
+    assert_eq!(status.source_rank(), previous_rank);
+
+    let gathered = if rank == 0 {
+        let (_, gathered) = root_process
+            .immediate_gather_into_root_owned(rank, vec![0; size as usize])
+            .wait_without_status();
+        Some(gathered)
+    } else {
+        root_process
+            .immediate_gather_into_owned(rank)
+            .wait_without_status();
+        None
+    };
+    if let Some(gathered) = gathered {
+        assert_eq!(gathered, (0..size).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,47 @@
+#![deny(warnings)]
+
+use mpi::info::Info;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+
+    let mut info = Info::new();
+    assert!(info.is_empty());
+    info.set("no_locks", "true").unwrap();
+    info.set("accumulate_ordering", "none").unwrap();
+    info.set("accumulate_ordering", "rar,raw").unwrap();
+    assert!(info.set("in\0valid", "true").is_err());
+
+    assert_eq!(info.len(), 2);
+    assert_eq!(info.get("no_locks").as_deref(), Some("true"));
+    assert_eq!(info.get("accumulate_ordering").as_deref(), Some("rar,raw"));
+    assert_eq!(info.get("wdir"), None);
+
+    let copy = info.clone();
+    assert!(info.delete("no_locks"));
+    assert!(!info.delete("no_locks"));
+    assert_eq!(info.len(), 1);
+    assert_eq!(copy.len(), 2);
+
+    let mut pairs: Vec<_> = copy.iter().collect();
+    pairs.sort();
+    assert_eq!(
+        pairs,
+        vec![
+            ("accumulate_ordering".to_string(), "rar,raw".to_string()),
+            ("no_locks".to_string(), "true".to_string()),
+        ]
+    );
+
+    let window = world.allocate_window_with_info::<u64>(4, &copy);
+    drop(window);
+
+    let comm = world.duplicate();
+    let mut hints = Info::new();
+    hints.set("mpi_assert_no_any_tag", "true").unwrap();
+    comm.set_info(&hints);
+    // MPI may ignore hints, so the hints in use cannot be checked
+    println!("Process {} uses hints {:?}", comm.rank(), comm.get_info());
+}
@@ -0,0 +1,81 @@
+#![deny(warnings)]
+
+#[cfg(mpi4)]
+fn main() {
+    use std::thread;
+
+    use mpi::partitioned::SendPartition;
+    use mpi::request;
+    use mpi::traits::*;
+    use mpi::{Count, Threading};
+
+    const PARTITIONS: Count = 4;
+    const PARTITION_LEN: usize = 3;
+
+    let (universe, threading) = mpi::initialize_with_threading(Threading::Multiple).unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+
+    let mut send = vec![0; PARTITIONS as usize * PARTITION_LEN];
+    let mut recv = vec![0; PARTITIONS as usize * PARTITION_LEN];
+
+    request::scope(|scope| {
+        let mut send = world.process_at_rank(next_rank).partitioned_send_init(
+            scope,
+            &mut send[..],
+            PARTITIONS,
+        );
+        let mut recv = world
+            .process_at_rank(previous_rank)
+            .partitioned_receive_init(scope, &mut recv[..], PARTITIONS);
+
+        for step in 0..2 {
+            let (recv_request, arrivals) = recv.start();
+            let (send_request, partitions) = send.start();
+
+            if threading == Threading::Multiple {
+                thread::scope(|s| {
+                    for mut partition in partitions {
+                        s.spawn(move || {
+                            let value = rank + partition.index() + step;
+                            partition.fill(value);
+                            partition.ready();
+                        });
+                    }
+                });
+            } else {
+                let partitions: Vec<_> = partitions
+                    .into_iter()
+                    .map(|mut partition| {
+                        let value = rank + partition.index() + step;
+                        partition.fill(value);
+                        partition
+                    })
+                    .collect();
+                SendPartition::ready_all(partitions);
+            }
+
+            let mut arrived = vec![false; PARTITIONS as usize];
+            while arrived.contains(&false) {
+                for partition in 0..PARTITIONS {
+                    if let Some(data) = arrivals.arrived(partition) {
+                        assert_eq!(data, [previous_rank + partition + step; PARTITION_LEN]);
+                        arrived[partition as usize] = true;
+                    }
+                }
+            }
+
+            send_request.wait_without_status();
+            recv_request.wait_without_status();
+        }
+    });
+
+    assert_eq!(recv[recv.len() - 1], previous_rank + PARTITIONS);
+}
+
+#[cfg(not(mpi4))]
+fn main() {}
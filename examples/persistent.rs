@@ -0,0 +1,54 @@
+#![deny(warnings)]
+
+use mpi::request;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+
+    let send = [rank, 2 * rank];
+    let mut recv = [0; 2];
+
+    request::scope(|scope| {
+        let mut requests = vec![
+            world
+                .process_at_rank(previous_rank)
+                .receive_init(scope, &mut recv[..]),
+            world.process_at_rank(next_rank).send_init(scope, &send[..]),
+        ];
+
+        for _ in 0..3 {
+            let mut started = request::start_all(&mut requests).into_iter();
+            let receive = started.next().unwrap();
+            let send = started.next().unwrap();
+            send.wait_without_status();
+            assert_eq!(receive.wait_for_data(), [previous_rank, 2 * previous_rank]);
+        }
+
+        for _ in 0..3 {
+            request::multiple_scope(2, |_, coll| {
+                for started in request::start_all(&mut requests) {
+                    coll.add(started);
+                }
+                let mut result = vec![];
+                coll.wait_all(&mut result);
+                assert_eq!(result.len(), 2);
+                assert_eq!(result[0].2, [previous_rank, 2 * previous_rank]);
+            });
+        }
+
+        let (receive, send) = requests.split_at_mut(1);
+        let receive = receive[0].start();
+        send[0].start().wait_without_status();
+        let status = receive.wait();
+        assert_eq!(status.source_rank(), previous_rank);
+    });
+
+    assert_eq!(recv, [previous_rank, 2 * previous_rank]);
+}
@@ -0,0 +1,72 @@
+#![deny(warnings)]
+
+use std::mem::MaybeUninit;
+
+use mpi::traits::*;
+
+const LEN: usize = 1000;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+
+    let msg: Vec<i32> = (0..LEN as i32).map(|x| x * rank).collect();
+    let expected: Vec<i32> = (0..LEN as i32).map(|x| x * previous_rank).collect();
+
+    // Receive into a buffer that is larger than the message.
+    mpi::request::scope(|scope| {
+        let send = world
+            .process_at_rank(next_rank)
+            .immediate_send(scope, &msg[..]);
+        let mut buf = [MaybeUninit::<i32>::uninit(); 2 * LEN];
+        let (received, status) = world
+            .process_at_rank(previous_rank)
+            .receive_into_uninit(&mut buf[..]);
+        assert_eq!(received, &expected[..]);
+        assert_eq!(status.source_rank(), previous_rank);
+        send.wait();
+    });
+
+    // Append to the spare capacity of a `Vec`.
+    mpi::request::scope(|scope| {
+        let send = world
+            .process_at_rank(next_rank)
+            .immediate_send(scope, &msg[..]);
+        let mut vec = Vec::with_capacity(LEN + 1);
+        vec.push(-1);
+        world
+            .process_at_rank(previous_rank)
+            .receive_into_spare_capacity(&mut vec);
+        assert_eq!(vec[0], -1);
+        assert_eq!(&vec[1..], &expected[..]);
+        send.wait();
+    });
+
+    let mut gathered = vec![MaybeUninit::<i32>::uninit(); size as usize];
+    let gathered = world.all_gather_into_uninit(&[rank], &mut gathered[..]);
+    assert_eq!(gathered, &(0..size).collect::<Vec<_>>()[..]);
+
+    let root_process = world.process_at_rank(0);
+    if rank == 0 {
+        let mut gathered = vec![MaybeUninit::<i32>::uninit(); size as usize];
+        let gathered = root_process.gather_into_root_uninit(&[rank * 2], &mut gathered[..]);
+        assert_eq!(gathered, &(0..size).map(|r| r * 2).collect::<Vec<_>>()[..]);
+    } else {
+        root_process.gather_into(&(rank * 2));
+    }
+
+    if rank == 0 {
+        let msg: Vec<i32> = (0..size).map(|r| r * 3).collect();
+        let scattered = root_process.scatter_vec_root(&msg[..]);
+        assert_eq!(scattered, vec![0]);
+    } else {
+        let mut buf = [MaybeUninit::<i32>::uninit(); 4];
+        let scattered = root_process.scatter_into_uninit(&mut buf[..]);
+        assert_eq!(scattered, &[rank * 3][..]);
+    }
+}
@@ -0,0 +1,44 @@
+#![deny(warnings)]
+
+use mpi::collective::SystemOperation;
+use mpi::datatype::ValueIndex;
+use mpi::traits::*;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    // The smallest value lives on the last rank, the largest on the first.
+    let value = f64::from(size - rank);
+    let local = ValueIndex::new(value, rank);
+
+    let mut min = ValueIndex::default();
+    world.all_reduce_into(&local, &mut min, SystemOperation::min_loc());
+    assert_eq!(min, ValueIndex::new(1.0, size - 1));
+
+    let mut max = ValueIndex::default();
+    world.all_reduce_into(&local, &mut max, SystemOperation::max_loc());
+    assert_eq!(max, ValueIndex::new(f64::from(size), 0));
+
+    // On ties, the lowest index wins.
+    let tied = ValueIndex::new(7i32, rank);
+    let mut min = ValueIndex::default();
+    world.all_reduce_into(&tied, &mut min, SystemOperation::min_loc());
+    assert_eq!(min, ValueIndex::new(7, 0));
+
+    let root_process = world.process_at_rank(0);
+    let local = [
+        ValueIndex::new(rank as f32, rank),
+        ValueIndex::new(-(rank as f32), rank),
+    ];
+    if rank == 0 {
+        let mut result = [ValueIndex::default(); 2];
+        root_process.reduce_into_root(&local[..], &mut result[..], SystemOperation::max_loc());
+        assert_eq!(result[0], ValueIndex::new((size - 1) as f32, size - 1));
+        assert_eq!(result[1], ValueIndex::new(0.0, 0));
+    } else {
+        root_process.reduce_into(&local[..], SystemOperation::max_loc());
+    }
+}
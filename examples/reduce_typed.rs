@@ -0,0 +1,62 @@
+#![deny(warnings)]
+
+use std::slice;
+
+use mpi::collective::{TypedUserOperation, UserReduction};
+use mpi::topology::Rank;
+use mpi::traits::*;
+
+struct Add;
+
+impl UserReduction for Add {
+    type Item = Rank;
+    const COMMUTATIVE: bool = true;
+
+    fn combine(invec: &[Rank], inoutvec: &mut [Rank]) {
+        for (&x, y) in invec.iter().zip(inoutvec) {
+            *y += x;
+        }
+    }
+}
+
+/// Keeps the left operand, so the result of a reduction is the contribution of the lowest rank.
+struct First;
+
+impl UserReduction for First {
+    type Item = f64;
+
+    fn combine(invec: &[f64], inoutvec: &mut [f64]) {
+        inoutvec.copy_from_slice(invec);
+    }
+}
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let add = TypedUserOperation::<Add>::new();
+    let mut sum = 0;
+    add.all_reduce_into(&world, &[rank + 1], slice::from_mut(&mut sum));
+    assert_eq!(sum, size * (size + 1) / 2);
+
+    let root = world.process_at_rank(0);
+    if rank == 0 {
+        let mut total = 0;
+        add.reduce_into_root(&root, &[rank], slice::from_mut(&mut total));
+        assert_eq!(total, size * (size - 1) / 2);
+    } else {
+        add.reduce_into(&root, &[rank]);
+    }
+
+    let first = TypedUserOperation::<First>::new();
+    let local = [f64::from(rank), f64::from(rank) * 2.0];
+    let mut result = [0.0; 2];
+    first.all_reduce_into(&world, &local, &mut result);
+    assert_eq!(result, [0.0, 0.0]);
+
+    let mut c = 1;
+    add.reduce_local_into(&[41], slice::from_mut(&mut c));
+    assert_eq!(c, 42);
+}
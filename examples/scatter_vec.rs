@@ -0,0 +1,36 @@
+#![deny(warnings)]
+
+use mpi::traits::*;
+use mpi::Count;
+
+fn main() {
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let root_rank = 0;
+    let root_process = world.process_at_rank(root_rank);
+
+    let rank = world.rank();
+    let size = world.size();
+
+    // Every rank receives two elements.
+    let part = if rank == root_rank {
+        let v: Vec<_> = (0..size * 2).collect();
+        root_process.scatter_vec_root(&v[..])
+    } else {
+        root_process.scatter_vec()
+    };
+    assert_eq!(part, vec![rank * 2, rank * 2 + 1]);
+
+    // Rank `r` receives `r` copies of `r`.
+    let part = if rank == root_rank {
+        let counts: Vec<Count> = (0..size).collect();
+        let v: Vec<_> = (0..size)
+            .flat_map(|r| std::iter::repeat(r).take(r as usize))
+            .collect();
+        root_process.scatter_varcount_vec_root(&v[..], &counts[..])
+    } else {
+        root_process.scatter_varcount_vec()
+    };
+    println!("Rank {} received {:?}.", rank, part);
+    assert_eq!(part, vec![rank; rank as usize]);
+}
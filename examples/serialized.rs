@@ -0,0 +1,59 @@
+#![deny(warnings)]
+
+#[cfg(all(feature = "bincode", feature = "cbor"))]
+fn main() {
+    use serde::{Deserialize, Serialize};
+
+    use mpi::serialization::{Bincode, Cbor};
+    use mpi::traits::*;
+
+    #[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+    struct Task {
+        name: String,
+        inputs: Vec<u64>,
+        retries: Option<u8>,
+    }
+
+    let universe = mpi::initialize().unwrap();
+    let world = universe.world();
+    let rank = world.rank();
+    let size = world.size();
+
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+
+    let task = |rank| Task {
+        name: format!("task {}", rank),
+        inputs: (0..rank as u64).collect(),
+        retries: if rank % 2 == 0 { Some(3) } else { None },
+    };
+
+    world
+        .process_at_rank(next_rank)
+        .send_serialized(&Bincode, &task(rank))
+        .unwrap();
+    let (received, status) = world
+        .process_at_rank(previous_rank)
+        .receive_deserialized::<_, Task>(&Bincode)
+        .unwrap();
+    assert_eq!(received, task(previous_rank));
+    assert_eq!(status.source_rank(), previous_rank);
+
+    let root_process = world.process_at_rank(0);
+
+    let mut config = if rank == 0 { task(42) } else { Task::default() };
+    root_process
+        .broadcast_serialized(&Cbor, &mut config)
+        .unwrap();
+    assert_eq!(config, task(42));
+
+    let gathered = root_process.gather_serialized(&Cbor, &task(rank)).unwrap();
+    if rank == 0 {
+        assert_eq!(gathered, Some((0..size).map(task).collect()));
+    } else {
+        assert_eq!(gathered, None);
+    }
+}
+
+#[cfg(not(all(feature = "bincode", feature = "cbor")))]
+fn main() {}
@@ -0,0 +1,53 @@
+#![deny(warnings)]
+
+#[cfg(mpi4)]
+fn main() {
+    use mpi::collective::SystemOperation;
+    use mpi::session::{Session, SELF_PROCESS_SET, WORLD_PROCESS_SET};
+    use mpi::topology::SimpleCommunicator;
+    use mpi::traits::*;
+
+    // No call to `mpi::initialize()`
+    let session = Session::init().unwrap();
+    let process_sets = session.process_sets();
+    assert!(process_sets.iter().any(|name| name == WORLD_PROCESS_SET));
+    assert!(process_sets.iter().any(|name| name == SELF_PROCESS_SET));
+
+    let size: i32 = session
+        .process_set_info(WORLD_PROCESS_SET)
+        .unwrap()
+        .get("mpi_size")
+        .unwrap()
+        .parse()
+        .unwrap();
+    // Safety: everything derived from a session is dropped before the session at the end
+    let group = unsafe { session.group(WORLD_PROCESS_SET) }.unwrap();
+    assert_eq!(group.size(), size);
+    assert!(unsafe { session.group("mpi://no-such-set") }.is_err());
+
+    let comm =
+        unsafe { SimpleCommunicator::from_group(&group, "org.rsmpi.examples.session") }.unwrap();
+    assert_eq!(comm.size(), size);
+    let mut sum = 0;
+    comm.all_reduce_into(&comm.rank(), &mut sum, SystemOperation::sum());
+    assert_eq!(sum, size * (size - 1) / 2);
+
+    // A library can open its own session independently
+    let library = Session::init().unwrap();
+    let library_group = unsafe { library.group(SELF_PROCESS_SET) }.unwrap();
+    let library_comm =
+        unsafe { SimpleCommunicator::from_group(&library_group, "org.rsmpi.examples.library") }
+            .unwrap();
+    assert_eq!(library_comm.size(), 1);
+
+    // Everything derived from a session has to be dropped before the session
+    drop(library_comm);
+    drop(library_group);
+    drop(library);
+    drop(comm);
+    drop(group);
+    drop(session);
+}
+
+#[cfg(not(mpi4))]
+fn main() {}
@@ -2,6 +2,7 @@
 use std::env;
 use std::process::Command;
 
+use mpi::info::Info;
 use mpi::topology::MergeOrder;
 use mpi::traits::*;
 
@@ -26,7 +27,11 @@ fn main() -> Result<(), mpi::MpiError> {
         let mut exe = Command::new(env::current_exe().unwrap());
         exe.arg("from_parent");
 
-        let child = world.process_at_rank(0).spawn(&exe, child_size)?;
+        let mut info = Info::new();
+        info.set("wdir", &env::current_dir().unwrap().to_string_lossy())?;
+        let child = world
+            .process_at_rank(0)
+            .spawn_with_info(&exe, child_size, &info)?;
 
         assert_eq!(child_size, child.remote_size());
 
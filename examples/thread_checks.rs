@@ -0,0 +1,23 @@
+#![deny(warnings)]
+
+use std::thread;
+
+use mpi::topology::SimpleCommunicator;
+use mpi::traits::*;
+use mpi::Threading;
+
+fn main() {
+    let (universe, threading) = mpi::initialize_with_threading(Threading::Funneled).unwrap();
+    universe.set_thread_checks(true);
+    let world = universe.world();
+
+    // The main thread may always use MPI
+    world.barrier();
+
+    // Other threads may only use MPI if at least `Threading::Serialized` is provided
+    let result = thread::spawn(|| SimpleCommunicator::world().rank()).join();
+    match threading {
+        Threading::Single | Threading::Funneled => assert!(result.is_err()),
+        Threading::Serialized | Threading::Multiple => assert_eq!(result.unwrap(), world.rank()),
+    }
+}
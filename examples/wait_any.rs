@@ -37,4 +37,90 @@ fn main() {
             println!("Process {} received data", world.rank());
         }
     });
+
+    const COUNT: usize = 4;
+    let rank = world.rank();
+    let size = world.size();
+    let next_rank = (rank + 1) % size;
+    let previous_rank = (rank - 1 + size) % size;
+    let values: Vec<f32> = (0..COUNT)
+        .map(|i| (rank as usize * COUNT + i) as f32)
+        .collect();
+    let expected: Vec<f32> = (0..COUNT)
+        .map(|i| (previous_rank as usize * COUNT + i) as f32)
+        .collect();
+
+    let mut received = vec![0.0; COUNT];
+    mpi::request::scope(|scope| {
+        let mut receives: Vec<_> = received
+            .iter_mut()
+            .map(|value| {
+                world
+                    .process_at_rank(previous_rank)
+                    .immediate_receive_into(scope, value)
+            })
+            .collect();
+        let mut sends: Vec<_> = values
+            .iter()
+            .map(|value| {
+                world
+                    .process_at_rank(next_rank)
+                    .immediate_send(scope, value)
+            })
+            .collect();
+
+        let mut sent = 0;
+        while !sends.is_empty() {
+            sent += mpi::request::wait_some(&mut sends).len();
+        }
+        assert_eq!(sent, COUNT);
+
+        let (index, _status) = mpi::request::wait_any(&mut receives).unwrap();
+        println!("Receive with index {} completed first", index);
+        // Peek at the other receives without completing them
+        for (i, receive) in receives.iter().enumerate() {
+            if let Some(status) = receive.get_status() {
+                println!("Receive with index {} from {}", i, status.source_rank());
+            }
+        }
+        let completed = mpi::request::wait_all(&mut receives);
+        assert_eq!(completed.len(), COUNT - 1);
+        assert!(receives.is_empty());
+    });
+    assert_eq!(received, expected);
+
+    let mut received = vec![0.0; COUNT];
+    mpi::request::scope(|scope| {
+        let mut receives: Vec<_> = received
+            .iter_mut()
+            .map(|value| {
+                world
+                    .process_at_rank(previous_rank)
+                    .immediate_receive_into(scope, value)
+            })
+            .collect();
+        let mut sends: Vec<_> = values
+            .iter()
+            .map(|value| {
+                world
+                    .process_at_rank(next_rank)
+                    .immediate_send(scope, value)
+            })
+            .collect();
+
+        let mut completed_receives = 0;
+        while !receives.is_empty() {
+            completed_receives += mpi::request::test_some(&mut receives).len();
+        }
+        assert_eq!(completed_receives, COUNT);
+
+        let completed = loop {
+            if let Some(completed) = mpi::request::test_all(&mut sends) {
+                break completed;
+            }
+        };
+        assert_eq!(completed.len(), COUNT);
+        assert!(sends.is_empty());
+    });
+    assert_eq!(received, expected);
 }
@@ -18,6 +18,11 @@ const MPI_Datatype RSMPI_UINT64_T = MPI_UINT64_T;
 const MPI_Datatype RSMPI_FLOAT_COMPLEX = MPI_C_FLOAT_COMPLEX;
 const MPI_Datatype RSMPI_DOUBLE_COMPLEX = MPI_C_DOUBLE_COMPLEX;
 
+const MPI_Datatype RSMPI_FLOAT_INT = MPI_FLOAT_INT;
+const MPI_Datatype RSMPI_DOUBLE_INT = MPI_DOUBLE_INT;
+const MPI_Datatype RSMPI_SHORT_INT = MPI_SHORT_INT;
+const MPI_Datatype RSMPI_2INT = MPI_2INT;
+
 const MPI_Datatype RSMPI_DATATYPE_NULL = MPI_DATATYPE_NULL;
 
 const MPI_Comm RSMPI_COMM_WORLD = MPI_COMM_WORLD;
@@ -69,6 +74,10 @@ const MPI_Op RSMPI_LOR = MPI_LOR;
 const MPI_Op RSMPI_BOR = MPI_BOR;
 const MPI_Op RSMPI_LXOR = MPI_LXOR;
 const MPI_Op RSMPI_BXOR = MPI_BXOR;
+const MPI_Op RSMPI_MINLOC = MPI_MINLOC;
+const MPI_Op RSMPI_MAXLOC = MPI_MAXLOC;
+const MPI_Op RSMPI_REPLACE = MPI_REPLACE;
+const MPI_Op RSMPI_NO_OP = MPI_NO_OP;
 
 const MPI_Errhandler RSMPI_ERRORS_ARE_FATAL = MPI_ERRORS_ARE_FATAL;
 const MPI_Errhandler RSMPI_ERRORS_RETURN = MPI_ERRORS_RETURN;
@@ -24,6 +24,11 @@ extern const MPI_Datatype RSMPI_UINT64_T;
 extern const MPI_Datatype RSMPI_FLOAT_COMPLEX;
 extern const MPI_Datatype RSMPI_DOUBLE_COMPLEX;
 
+extern const MPI_Datatype RSMPI_FLOAT_INT;
+extern const MPI_Datatype RSMPI_DOUBLE_INT;
+extern const MPI_Datatype RSMPI_SHORT_INT;
+extern const MPI_Datatype RSMPI_2INT;
+
 extern const MPI_Datatype RSMPI_DATATYPE_NULL;
 
 extern const MPI_Comm RSMPI_COMM_WORLD;
@@ -75,6 +80,10 @@ extern const MPI_Op RSMPI_LOR;
 extern const MPI_Op RSMPI_BOR;
 extern const MPI_Op RSMPI_LXOR;
 extern const MPI_Op RSMPI_BXOR;
+extern const MPI_Op RSMPI_MINLOC;
+extern const MPI_Op RSMPI_MAXLOC;
+extern const MPI_Op RSMPI_REPLACE;
+extern const MPI_Op RSMPI_NO_OP;
 
 extern const MPI_Errhandler RSMPI_ERRORS_ARE_FATAL;
 extern const MPI_Errhandler RSMPI_ERRORS_RETURN;
@@ -157,6 +157,74 @@ unsafe impl AsRaw for AttributeKey {
     }
 }
 
+/// For obtaining the tag upper bound attribute of MPI_COMM_WORLD
+#[repr(C)]
+#[derive(Clone)]
+pub(crate) struct TagUpperBound(c_int);
+
+impl CommAttribute for TagUpperBound {
+    fn get_key() -> AttributeKey {
+        unsafe { AttributeKey::new_unchecked(ffi::MPI_TAG_UB as i32) }
+    }
+}
+
+impl From<&TagUpperBound> for c_int {
+    fn from(ub: &TagUpperBound) -> Self {
+        ub.0
+    }
+}
+
+/// For obtaining the host attribute of MPI_COMM_WORLD
+#[repr(C)]
+#[derive(Clone)]
+pub(crate) struct Host(c_int);
+
+impl CommAttribute for Host {
+    fn get_key() -> AttributeKey {
+        unsafe { AttributeKey::new_unchecked(ffi::MPI_HOST as i32) }
+    }
+}
+
+impl From<&Host> for c_int {
+    fn from(host: &Host) -> Self {
+        host.0
+    }
+}
+
+/// For obtaining the I/O rank attribute of MPI_COMM_WORLD
+#[repr(C)]
+#[derive(Clone)]
+pub(crate) struct IoRank(c_int);
+
+impl CommAttribute for IoRank {
+    fn get_key() -> AttributeKey {
+        unsafe { AttributeKey::new_unchecked(ffi::MPI_IO as i32) }
+    }
+}
+
+impl From<&IoRank> for c_int {
+    fn from(io: &IoRank) -> Self {
+        io.0
+    }
+}
+
+/// For obtaining the clock synchronization attribute of MPI_COMM_WORLD
+#[repr(C)]
+#[derive(Clone)]
+pub(crate) struct WtimeIsGlobal(c_int);
+
+impl CommAttribute for WtimeIsGlobal {
+    fn get_key() -> AttributeKey {
+        unsafe { AttributeKey::new_unchecked(ffi::MPI_WTIME_IS_GLOBAL as i32) }
+    }
+}
+
+impl From<&WtimeIsGlobal> for bool {
+    fn from(global: &WtimeIsGlobal) -> Self {
+        global.0 != 0
+    }
+}
+
 /// For obtaining the universe size attribute
 #[repr(C)]
 #[derive(Clone)]
@@ -0,0 +1,278 @@
+//! Typed message channels
+//!
+//! Several logical streams of messages between the same processes are usually told apart by
+//! their tags.  `Channels` manages such streams on a duplicate of a communicator, so they cannot
+//! interfere with other messages on the original communicator.  Each channel reserves a tag and
+//! is split into a `Sender` and a `Receiver` half that transfer values of a single type.
+//!
+//! Channels are opened through a `Channels` value rather than directly on a communicator, since
+//! the duplicate communicator and the set of reserved tags need an owner that outlives the
+//! channels.  A tag is released once both halves of its channel have been dropped.
+//!
+//! # Examples
+//!
+//! See `examples/channel.rs`
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::attribute::TagUpperBound;
+use crate::datatype::traits::*;
+use crate::environment::{is_finalized, is_initialized};
+use crate::point_to_point::{traits::*, Status};
+use crate::request::{Request, Scope};
+use crate::topology::{traits::*, Rank, SimpleCommunicator};
+use crate::{MpiError, Tag};
+
+/// A set of typed channels on a duplicate of a communicator
+///
+/// # Examples
+///
+/// See `examples/channel.rs`
+pub struct Channels {
+    comm: SimpleCommunicator,
+    tag_ub: Tag,
+    // The number of live halves of the channel for each reserved tag
+    reserved: RefCell<BTreeMap<Tag, usize>>,
+}
+
+impl fmt::Debug for Channels {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("Channels")
+            .field("tag_ub", &self.tag_ub)
+            .field("reserved", &self.reserved.borrow())
+            .finish()
+    }
+}
+
+impl Channels {
+    /// Create a set of channels between the processes of `comm`.
+    ///
+    /// This duplicates `comm` and thus has to be called on all processes of `comm`.
+    ///
+    /// Fails if `MPI_TAG_UB` is neither set on `comm` nor, while the World Model is initialized,
+    /// on `MPI_COMM_WORLD`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.2, 8.1.2
+    pub fn new<C: Communicator + ?Sized>(comm: &C) -> Result<Channels, MpiError> {
+        let comm = comm.duplicate();
+        // Implementations before MPI 4.0 may only cache `MPI_TAG_UB` on `MPI_COMM_WORLD`, which
+        // does not exist in programs that only use sessions.
+        let tag_ub = comm
+            .get_attr::<TagUpperBound>()
+            .map(Tag::from)
+            .or_else(|| {
+                if is_initialized() && !is_finalized() {
+                    SimpleCommunicator::world()
+                        .get_attr::<TagUpperBound>()
+                        .map(Tag::from)
+                } else {
+                    None
+                }
+            })
+            .ok_or(MpiError::AttributeNotSet("MPI_TAG_UB"))?;
+        Ok(Channels {
+            comm,
+            tag_ub,
+            reserved: RefCell::new(BTreeMap::new()),
+        })
+    }
+
+    /// The largest tag that can be used for a channel, i.e. `MPI_TAG_UB`
+    pub fn tag_ub(&self) -> Tag {
+        self.tag_ub
+    }
+
+    /// The duplicated communicator the channels communicate on
+    pub fn communicator(&self) -> &SimpleCommunicator {
+        &self.comm
+    }
+
+    /// Open a channel for values of type `T` identified by `tag`.
+    ///
+    /// The tag stays reserved until both the `Sender` and the `Receiver` have been dropped.
+    /// Processes communicating through the channel have to open it with the same tag.
+    ///
+    /// Fails if `tag` is outside of the range `0..=MPI_TAG_UB` or has already been reserved.
+    pub fn channel<T: Equivalence>(
+        &self,
+        tag: Tag,
+    ) -> Result<(Sender<'_, T>, Receiver<'_, T>), MpiError> {
+        if !(0..=self.tag_ub).contains(&tag) {
+            return Err(MpiError::TagOutOfRange(tag, self.tag_ub));
+        }
+        let mut reserved = self.reserved.borrow_mut();
+        if reserved.contains_key(&tag) {
+            return Err(MpiError::TagInUse(tag));
+        }
+        reserved.insert(tag, 2);
+        Ok((
+            Sender {
+                channels: self,
+                tag,
+                phantom: PhantomData,
+            },
+            Receiver {
+                channels: self,
+                tag,
+                phantom: PhantomData,
+            },
+        ))
+    }
+
+    /// Release `tag` once both halves of its channel have been dropped.
+    fn release(&self, tag: Tag) {
+        let mut reserved = self.reserved.borrow_mut();
+        let halves = reserved
+            .get_mut(&tag)
+            .expect("rsmpi internal error: channel tag is not reserved");
+        *halves -= 1;
+        if *halves == 0 {
+            reserved.remove(&tag);
+        }
+    }
+}
+
+/// The sending half of a channel for values of type `T`
+pub struct Sender<'c, T> {
+    channels: &'c Channels,
+    tag: Tag,
+    phantom: PhantomData<fn(&T)>,
+}
+
+impl<'c, T> fmt::Debug for Sender<'c, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("Sender")
+            .field("tag", &self.tag)
+            .finish()
+    }
+}
+
+impl<'c, T> Drop for Sender<'c, T> {
+    fn drop(&mut self) {
+        self.channels.release(self.tag);
+    }
+}
+
+impl<'c, T: Equivalence> Sender<'c, T> {
+    /// The tag of the channel
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Send `msg` to the process at rank `destination`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    pub fn send(&self, destination: Rank, msg: &T) {
+        self.channels
+            .comm
+            .process_at_rank(destination)
+            .send_with_tag(msg, self.tag)
+    }
+
+    /// Initiate sending `msg` to the process at rank `destination`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    pub fn immediate_send<'a, Sc>(
+        &self,
+        scope: Sc,
+        destination: Rank,
+        msg: &'a T,
+    ) -> Request<'a, T, Sc>
+    where
+        Sc: Scope<'a>,
+    {
+        self.channels
+            .comm
+            .process_at_rank(destination)
+            .immediate_send_with_tag(scope, msg, self.tag)
+    }
+}
+
+/// The receiving half of a channel for values of type `T`
+pub struct Receiver<'c, T> {
+    channels: &'c Channels,
+    tag: Tag,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<'c, T> fmt::Debug for Receiver<'c, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("Receiver")
+            .field("tag", &self.tag)
+            .finish()
+    }
+}
+
+impl<'c, T> Drop for Receiver<'c, T> {
+    fn drop(&mut self) {
+        self.channels.release(self.tag);
+    }
+}
+
+impl<'c, T: Equivalence> Receiver<'c, T> {
+    /// The tag of the channel
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Receive a value from any process.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    pub fn receive(&self) -> (T, Status) {
+        self.channels.comm.any_process().receive_with_tag(self.tag)
+    }
+
+    /// Receive a value from the process at rank `source`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    pub fn receive_from(&self, source: Rank) -> (T, Status) {
+        self.channels
+            .comm
+            .process_at_rank(source)
+            .receive_with_tag(self.tag)
+    }
+
+    /// Receive a value from any process if one has arrived.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.2, 3.8.3
+    pub fn try_receive(&self) -> Option<(T, Status)> {
+        self.channels
+            .comm
+            .any_process()
+            .immediate_matched_probe_with_tag(self.tag)
+            .map(|(message, _)| message.matched_receive())
+    }
+
+    /// Initiate receiving a value from any process into `buf`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    pub fn immediate_receive_into<'a, Sc>(&self, scope: Sc, buf: &'a mut T) -> Request<'a, T, Sc>
+    where
+        Sc: Scope<'a>,
+    {
+        self.channels
+            .comm
+            .any_process()
+            .immediate_receive_into_with_tag(scope, buf, self.tag)
+    }
+}
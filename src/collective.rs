@@ -10,24 +10,31 @@
 //! `MPI_Ialltoallw()`, `MPI_Ireduce_scatter()`
 
 use std::ffi::{CString, NulError};
-#[cfg(feature = "user-operations")]
-use std::mem;
+use std::marker::PhantomData;
+use std::mem::{self, MaybeUninit};
 use std::os::raw::{c_char, c_int, c_void};
-use std::process::Command;
-use std::{fmt, ptr};
+use std::panic::{self, AssertUnwindSafe};
+use std::process::{self, Command};
+use std::{fmt, ptr, slice};
 
 use conv::ConvUtil;
 #[cfg(feature = "user-operations")]
 use libffi::middle::{Cif, Closure, Type};
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
 
-use crate::ffi::MPI_Op;
-use crate::{ffi, MpiError};
+use crate::ffi::{MPI_Info, MPI_Op};
+use crate::{check, ffi, Count, MpiError};
 
 use crate::datatype::traits::*;
+use crate::datatype::{assume_init_prefix, assume_init_vec, uninit_vec, Partition, PartitionMut};
 #[cfg(feature = "user-operations")]
 use crate::datatype::{DatatypeRef, DynBuffer, DynBufferMut};
+use crate::info::Info;
 use crate::raw::traits::*;
-use crate::request::{Request, Scope, StaticScope};
+use crate::request::{OwnedRequest, Request, Scope, StaticScope};
+#[cfg(feature = "serde")]
+use crate::serialization::{CollectiveError, WireFormat};
 use crate::topology::{traits::*, InterCommunicator};
 use crate::topology::{Process, Rank};
 use crate::with_uninitialized;
@@ -59,6 +66,22 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Barrier synchronization among all processes in a `Communicator` that reports errors
+    ///
+    /// Returns an error if the barrier fails and the error handler of the communicator returns
+    /// errors.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handling.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.3, 8.3
+    fn try_barrier(&self) -> Result<(), MpiError> {
+        check("MPI_Barrier", unsafe { ffi::MPI_Barrier(self.as_raw()) })
+    }
+
     /// Gather contents of buffers on all participating processes.
     ///
     /// After the call completes, the contents of the send `Buffer`s on all processes will be
@@ -91,6 +114,59 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Gather contents of slices on all participating processes into uninitialized memory.
+    ///
+    /// Like `all_gather_into`, but returns the prefix of `recvbuf` that holds the gathered values
+    /// once the call has completed. All send slices must contain the same count of elements and
+    /// `recvbuf` must have room for that count times the size of the `Communicator`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/receive_uninit.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.7
+    fn all_gather_into_uninit<'b, T>(
+        &self,
+        sendbuf: &[T],
+        recvbuf: &'b mut [MaybeUninit<T>],
+    ) -> &'b mut [T]
+    where
+        T: Equivalence,
+    {
+        let len = sendbuf.len() * target_len(self);
+        assert!(
+            recvbuf.len() >= len,
+            "Receive buffer is too small for the gathered elements."
+        );
+        self.all_gather_into(sendbuf, &mut recvbuf[..len]);
+        unsafe { assume_init_prefix(recvbuf, len) }
+    }
+
+    /// Gather contents of slices on all participating processes into a newly allocated `Vec`.
+    ///
+    /// After the call completes, the returned `Vec` on all ranks holds the concatenation of the
+    /// send slices of all processes, in order of their ranks.
+    ///
+    /// All send slices must contain the same count of elements.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/all_gather_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.7
+    fn all_gather_vec<T>(&self, sendbuf: &[T]) -> Vec<T>
+    where
+        T: Equivalence,
+    {
+        let mut res = uninit_vec::<T>(sendbuf.len() * target_len(self));
+        self.all_gather_into(sendbuf, &mut res[..]);
+        unsafe { assume_init_vec(res) }
+    }
+
     /// Gather contents of buffers on all participating processes.
     ///
     /// After the call completes, the contents of the send `Buffer`s on all processes will be
@@ -125,6 +201,35 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Gather contents of slices on all participating processes into a newly allocated `Vec`.
+    ///
+    /// The send slices may contain different counts of elements on different processes. The
+    /// counts are exchanged internally and returned alongside the concatenated elements, so that
+    /// the part contributed by each rank can be identified.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/all_gather_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.7
+    fn all_gather_varcount_vec<T>(&self, sendbuf: &[T]) -> (Vec<T>, Vec<Count>)
+    where
+        T: Equivalence,
+    {
+        let mut counts: Vec<Count> = vec![0; target_len(self)];
+        self.all_gather_into(&sendbuf.count(), &mut counts[..]);
+        let displs = displacements(&counts);
+
+        let mut res = uninit_vec::<T>(total_count(&counts));
+        {
+            let mut partition = PartitionMut::new(&mut res[..], &counts[..], &displs[..]);
+            self.all_gather_varcount_into(sendbuf, &mut partition);
+        }
+        (unsafe { assume_init_vec(res) }, counts)
+    }
+
     /// Distribute the send `Buffer`s from all processes to the receive `Buffer`s on all processes.
     ///
     /// Each process sends and receives the same count of elements to and from each process.
@@ -155,6 +260,26 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Distribute the send slices from all processes to newly allocated `Vec`s on all processes.
+    ///
+    /// Each process sends and receives the same count of elements to and from each process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/all_gather_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.8
+    fn all_to_all_vec<T>(&self, sendbuf: &[T]) -> Vec<T>
+    where
+        T: Equivalence,
+    {
+        let mut res = uninit_vec::<T>(sendbuf.len());
+        self.all_to_all_into(sendbuf, &mut res[..]);
+        unsafe { assume_init_vec(res) }
+    }
+
     /// Distribute the send `Buffer`s from all processes to the receive `Buffer`s on all processes.
     ///
     /// The count of elements to send and receive to and from each process can vary and is specified
@@ -183,6 +308,41 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Distribute the send slices from all processes to newly allocated `Vec`s on all processes.
+    ///
+    /// `sendcounts` holds the count of elements to send to each process, in order of rank; the
+    /// elements are taken from `sendbuf` consecutively. The receive counts are exchanged
+    /// internally and returned alongside the received elements.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/all_gather_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.8
+    fn all_to_all_varcount_vec<T>(
+        &self,
+        sendbuf: &[T],
+        sendcounts: &[Count],
+    ) -> (Vec<T>, Vec<Count>)
+    where
+        T: Equivalence,
+    {
+        let mut recvcounts: Vec<Count> = vec![0; target_len(self)];
+        self.all_to_all_into(sendcounts, &mut recvcounts[..]);
+        let sdispls = displacements(sendcounts);
+        let rdispls = displacements(&recvcounts);
+
+        let mut res = uninit_vec::<T>(total_count(&recvcounts));
+        {
+            let partition = Partition::new(sendbuf, sendcounts, &sdispls[..]);
+            let mut recv_partition = PartitionMut::new(&mut res[..], &recvcounts[..], &rdispls[..]);
+            self.all_to_all_varcount_into(&partition, &mut recv_partition);
+        }
+        (unsafe { assume_init_vec(res) }, recvcounts)
+    }
+
     /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
     /// stores the result in `recvbuf` on all processes.
     ///
@@ -211,6 +371,67 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
+    /// stores the result in `recvbuf` on all processes, reporting errors.
+    ///
+    /// Returns an error if the reduction fails and the error handler of the communicator returns
+    /// errors.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.6, 8.3
+    fn try_all_reduce_into<S: ?Sized, R: ?Sized, O>(
+        &self,
+        sendbuf: &S,
+        recvbuf: &mut R,
+        op: O,
+    ) -> Result<(), MpiError>
+    where
+        S: Buffer,
+        R: BufferMut,
+        O: Operation,
+    {
+        let code = unsafe {
+            ffi::MPI_Allreduce(
+                sendbuf.pointer(),
+                recvbuf.pointer_mut(),
+                sendbuf.count(),
+                sendbuf.as_datatype().as_raw(),
+                op.as_raw(),
+                self.as_raw(),
+            )
+        };
+        check("MPI_Allreduce", code)
+    }
+
+    /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
+    /// stores the result in uninitialized memory `recvbuf` on all processes.
+    ///
+    /// Like `all_reduce_into`, but returns the prefix of `recvbuf` that holds the reduced values
+    /// once the call has completed. `recvbuf` must be at least as long as `sendbuf`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.6
+    fn all_reduce_into_uninit<'b, T, O>(
+        &self,
+        sendbuf: &[T],
+        recvbuf: &'b mut [MaybeUninit<T>],
+        op: O,
+    ) -> &'b mut [T]
+    where
+        T: Equivalence,
+        O: Operation,
+    {
+        let len = sendbuf.len();
+        assert!(
+            recvbuf.len() >= len,
+            "Receive buffer is too small for the reduced elements."
+        );
+        self.all_reduce_into(sendbuf, &mut recvbuf[..len], op);
+        unsafe { assume_init_prefix(recvbuf, len) }
+    }
+
     /// Performs an element-wise global reduction under the operation `op` of the input data in
     /// `sendbuf` and scatters the result into equal sized blocks in the receive buffers on all
     /// processes.
@@ -366,6 +587,31 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Initiate non-blocking gather of the contents of all `sendbuf`s into all `recvbuf`s on all
+    /// processes in the communicator.
+    ///
+    /// Unlike `immediate_all_gather_into`, the buffers are moved into the returned `OwnedRequest`
+    /// and handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.5
+    fn immediate_all_gather_into_owned<S, R>(&self, sendbuf: S, recvbuf: R) -> OwnedRequest<(S, R)>
+    where
+        S: 'static + Buffer,
+        R: 'static + BufferMut,
+    {
+        unsafe {
+            OwnedRequest::start((sendbuf, recvbuf), |(sendbuf, recvbuf)| {
+                self.immediate_all_gather_into(StaticScope, &*sendbuf, recvbuf)
+            })
+        }
+    }
+
     /// Initiate non-blocking gather of the contents of all `sendbuf`s into all `rcevbuf`s on all
     /// processes in the communicator.
     ///
@@ -451,6 +697,30 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Initiate non-blocking all-to-all communication.
+    ///
+    /// Unlike `immediate_all_to_all_into`, the buffers are moved into the returned `OwnedRequest`
+    /// and handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.6
+    fn immediate_all_to_all_into_owned<S, R>(&self, sendbuf: S, recvbuf: R) -> OwnedRequest<(S, R)>
+    where
+        S: 'static + Buffer,
+        R: 'static + BufferMut,
+    {
+        unsafe {
+            OwnedRequest::start((sendbuf, recvbuf), |(sendbuf, recvbuf)| {
+                self.immediate_all_to_all_into(StaticScope, &*sendbuf, recvbuf)
+            })
+        }
+    }
+
     /// Initiate non-blocking all-to-all communication.
     ///
     /// # Standard section(s)
@@ -533,6 +803,37 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Initiates a non-blocking global reduction under the operation `op` of the input data in
+    /// `sendbuf` and stores the result in `recvbuf` on all processes.
+    ///
+    /// Unlike `immediate_all_reduce_into`, the buffers are moved into the returned `OwnedRequest`
+    /// and handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.8
+    fn immediate_all_reduce_into_owned<S, R, O>(
+        &self,
+        sendbuf: S,
+        recvbuf: R,
+        op: O,
+    ) -> OwnedRequest<(S, R)>
+    where
+        S: 'static + Buffer,
+        R: 'static + BufferMut,
+        O: 'static + Operation,
+    {
+        unsafe {
+            OwnedRequest::start((sendbuf, recvbuf), |(sendbuf, recvbuf)| {
+                self.immediate_all_reduce_into(StaticScope, &*sendbuf, recvbuf, op)
+            })
+        }
+    }
+
     /// Initiates a non-blocking element-wise global reduction under the operation `op` of the
     /// input data in `sendbuf` and scatters the result into equal sized blocks in the receive
     /// buffers on all processes.
@@ -578,6 +879,38 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Initiates a non-blocking element-wise global reduction under the operation `op` of the
+    /// input data in `sendbuf` and scatters the result into equal sized blocks in the receive
+    /// buffers on all processes.
+    ///
+    /// Unlike `immediate_reduce_scatter_block_into`, the buffers are moved into the returned
+    /// `OwnedRequest` and handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.9
+    fn immediate_reduce_scatter_block_into_owned<S, R, O>(
+        &self,
+        sendbuf: S,
+        recvbuf: R,
+        op: O,
+    ) -> OwnedRequest<(S, R)>
+    where
+        S: 'static + Buffer,
+        R: 'static + BufferMut,
+        O: 'static + Operation,
+    {
+        unsafe {
+            OwnedRequest::start((sendbuf, recvbuf), |(sendbuf, recvbuf)| {
+                self.immediate_reduce_scatter_block_into(StaticScope, &*sendbuf, recvbuf, op)
+            })
+        }
+    }
+
     /// Initiates a non-blocking global inclusive prefix reduction of the data in `sendbuf` into
     /// `recvbuf` under operation `op`.
     ///
@@ -621,6 +954,37 @@ pub trait CommunicatorCollectives: Communicator {
         }
     }
 
+    /// Initiates a non-blocking inclusive prefix reduction of the data in `sendbuf` into
+    /// `recvbuf` under operation `op`.
+    ///
+    /// Unlike `immediate_scan_into`, the buffers are moved into the returned `OwnedRequest` and
+    /// handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.11
+    fn immediate_scan_into_owned<S, R, O>(
+        &self,
+        sendbuf: S,
+        recvbuf: R,
+        op: O,
+    ) -> OwnedRequest<(S, R)>
+    where
+        S: 'static + Buffer,
+        R: 'static + BufferMut,
+        O: 'static + Operation,
+    {
+        unsafe {
+            OwnedRequest::start((sendbuf, recvbuf), |(sendbuf, recvbuf)| {
+                self.immediate_scan_into(StaticScope, &*sendbuf, recvbuf, op)
+            })
+        }
+    }
+
     /// Initiates a non-blocking global exclusive prefix reduction of the data in `sendbuf` into
     /// `recvbuf` under operation `op`.
     ///
@@ -663,6 +1027,37 @@ pub trait CommunicatorCollectives: Communicator {
             )
         }
     }
+
+    /// Initiates a non-blocking exclusive prefix reduction of the data in `sendbuf` into
+    /// `recvbuf` under operation `op`.
+    ///
+    /// Unlike `immediate_exclusive_scan_into`, the buffers are moved into the returned
+    /// `OwnedRequest` and handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.12
+    fn immediate_exclusive_scan_into_owned<S, R, O>(
+        &self,
+        sendbuf: S,
+        recvbuf: R,
+        op: O,
+    ) -> OwnedRequest<(S, R)>
+    where
+        S: 'static + Buffer,
+        R: 'static + BufferMut,
+        O: 'static + Operation,
+    {
+        unsafe {
+            OwnedRequest::start((sendbuf, recvbuf), |(sendbuf, recvbuf)| {
+                self.immediate_exclusive_scan_into(StaticScope, &*sendbuf, recvbuf, op)
+            })
+        }
+    }
 }
 
 impl<C: Communicator + ?Sized> CommunicatorCollectives for C {}
@@ -702,45 +1097,120 @@ pub trait Root: AsCommunicator {
         }
     }
 
-    /// Gather contents of buffers on `Root`.
-    ///
-    /// After the call completes, the contents of the `Buffer`s on all ranks will be
-    /// concatenated into the `Buffer` on `Root`.
-    ///
-    /// All send `Buffer`s must have the same count of elements.
-    ///
-    /// This function must be called on all non-root processes.
-    ///
-    /// # Examples
+    /// Broadcast of the contents of a buffer that reports errors
     ///
-    /// See `examples/gather.rs`
+    /// Returns an error if the broadcast fails and the error handler of the communicator returns
+    /// errors.
     ///
     /// # Standard section(s)
     ///
-    /// 5.5
-    fn gather_into<S: ?Sized>(&self, sendbuf: &S)
+    /// 5.4, 8.3
+    fn try_broadcast_into<Buf: ?Sized>(&self, buffer: &mut Buf) -> Result<(), MpiError>
     where
-        S: Buffer,
+        Buf: BufferMut,
     {
-        assert_ne!(self.as_communicator().rank(), self.root_rank());
-        unsafe {
-            ffi::MPI_Gather(
-                sendbuf.pointer(),
-                sendbuf.count(),
-                sendbuf.as_datatype().as_raw(),
-                ptr::null_mut(),
-                0,
-                u8::equivalent_datatype().as_raw(),
+        let code = unsafe {
+            ffi::MPI_Bcast(
+                buffer.pointer_mut(),
+                buffer.count(),
+                buffer.as_datatype().as_raw(),
                 self.root_rank(),
                 self.as_communicator().as_raw(),
-            );
-        }
+            )
+        };
+        check("MPI_Bcast", code)
     }
 
-    /// Gather contents of buffers on `Root`.
+    /// Broadcast of a `Vec` whose length is only known on the `Root`
     ///
-    /// After the call completes, the contents of the `Buffer`s on all ranks will be
-    /// concatenated into the `Buffer` on `Root`.
+    /// The length is broadcast first. After the call completes, `vec` on all processes in the
+    /// `Communicator` of the `Root` `&self` will contain what it contains on the `Root`. Its previous
+    /// contents on non-root processes are discarded.
+    ///
+    /// This function must be called on all processes. It only supports intra-communicators.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/broadcast_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4
+    fn broadcast_vec<T>(&self, vec: &mut Vec<T>)
+    where
+        T: Equivalence,
+    {
+        if is_local_root(self) {
+            let mut len = vec.count();
+            self.broadcast_into(&mut len);
+            self.broadcast_into(&mut vec[..]);
+        } else {
+            let mut len: Count = 0;
+            self.broadcast_into(&mut len);
+            let mut res = uninit_vec::<T>(count_len(len));
+            self.broadcast_into(&mut res[..]);
+            *vec = unsafe { assume_init_vec(res) };
+        }
+    }
+
+    /// Broadcast of a `String` whose length is only known on the `Root`
+    ///
+    /// After the call completes, `string` on all processes in the `Communicator` of the `Root`
+    /// `&self` will contain what it contains on the `Root`. Its previous contents on non-root
+    /// processes are discarded.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/broadcast_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4
+    fn broadcast_string(&self, string: &mut String) {
+        let mut bytes = mem::take(string).into_bytes();
+        self.broadcast_vec(&mut bytes);
+        *string = String::from_utf8(bytes).expect("Broadcast string is not valid UTF-8.");
+    }
+
+    /// Gather contents of buffers on `Root`.
+    ///
+    /// After the call completes, the contents of the `Buffer`s on all ranks will be
+    /// concatenated into the `Buffer` on `Root`.
+    ///
+    /// All send `Buffer`s must have the same count of elements.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/gather.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    fn gather_into<S: ?Sized>(&self, sendbuf: &S)
+    where
+        S: Buffer,
+    {
+        assert_ne!(self.as_communicator().rank(), self.root_rank());
+        unsafe {
+            ffi::MPI_Gather(
+                sendbuf.pointer(),
+                sendbuf.count(),
+                sendbuf.as_datatype().as_raw(),
+                ptr::null_mut(),
+                0,
+                u8::equivalent_datatype().as_raw(),
+                self.root_rank(),
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
+    /// Gather contents of buffers on `Root`.
+    ///
+    /// After the call completes, the contents of the `Buffer`s on all ranks will be
+    /// concatenated into the `Buffer` on `Root`.
     ///
     /// All send `Buffer`s must have the same count of elements.
     ///
@@ -774,6 +1244,69 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Gather contents of buffers into uninitialized memory on `Root`.
+    ///
+    /// Like `gather_into_root`, but returns the prefix of `recvbuf` that holds the gathered
+    /// values once the call has completed. All send slices must contain the same count of
+    /// elements and `recvbuf` must have room for that count times the size of the `Communicator`.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/receive_uninit.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    fn gather_into_root_uninit<'b, T>(
+        &self,
+        sendbuf: &[T],
+        recvbuf: &'b mut [MaybeUninit<T>],
+    ) -> &'b mut [T]
+    where
+        T: Equivalence,
+    {
+        let len = sendbuf.len() * target_len(self.as_communicator());
+        assert!(
+            recvbuf.len() >= len,
+            "Receive buffer is too small for the gathered elements."
+        );
+        self.gather_into_root(sendbuf, &mut recvbuf[..len]);
+        unsafe { assume_init_prefix(recvbuf, len) }
+    }
+
+    /// Gather contents of slices on `Root` into a newly allocated `Vec`.
+    ///
+    /// After the call completes, the `Vec` returned on `Root` holds the concatenation of the send
+    /// slices of all processes, in order of their ranks. All other processes receive `None`.
+    ///
+    /// All send slices must have the same count of elements.
+    ///
+    /// This function must be called on all processes. It only supports intra-communicators.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/gather_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    fn gather_vec<T>(&self, sendbuf: &[T]) -> Option<Vec<T>>
+    where
+        T: Equivalence,
+    {
+        if is_local_root(self) {
+            let size = target_len(self.as_communicator());
+            let mut res = uninit_vec::<T>(sendbuf.len() * size);
+            self.gather_into_root(sendbuf, &mut res[..]);
+            Some(unsafe { assume_init_vec(res) })
+        } else {
+            self.gather_into(sendbuf);
+            None
+        }
+    }
+
     /// Gather contents of buffers on `Root`.
     ///
     /// After the call completes, the contents of the `Buffer`s on all ranks will be
@@ -849,6 +1382,44 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Gather contents of slices on `Root` into a newly allocated `Vec`.
+    ///
+    /// The send slices may contain different counts of elements on different processes. The
+    /// counts are gathered internally and returned on `Root` alongside the concatenated elements.
+    /// All other processes receive `None`.
+    ///
+    /// This function must be called on all processes. It only supports intra-communicators.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/gather_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    fn gather_varcount_vec<T>(&self, sendbuf: &[T]) -> Option<(Vec<T>, Vec<Count>)>
+    where
+        T: Equivalence,
+    {
+        if is_local_root(self) {
+            let size = target_len(self.as_communicator());
+            let mut counts: Vec<Count> = vec![0; size];
+            self.gather_into_root(&sendbuf.count(), &mut counts[..]);
+            let displs = displacements(&counts);
+
+            let mut res = uninit_vec::<T>(total_count(&counts));
+            {
+                let mut partition = PartitionMut::new(&mut res[..], &counts[..], &displs[..]);
+                self.gather_varcount_into_root(sendbuf, &mut partition);
+            }
+            Some((unsafe { assume_init_vec(res) }, counts))
+        } else {
+            self.gather_into(&sendbuf.count());
+            self.gather_varcount_into(sendbuf);
+            None
+        }
+    }
+
     /// Scatter contents of a buffer on the root process to all processes.
     ///
     /// After the call completes each participating process will have received a part of the send
@@ -884,6 +1455,33 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Scatter contents of a buffer on the root process into uninitialized memory on all
+    /// processes.
+    ///
+    /// Like `scatter_vec`, but receives into `recvbuf` and returns the prefix of it that holds
+    /// the received values. The count of elements each process receives is broadcast by the root
+    /// process first, so the root process must call `scatter_vec_root`.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.6
+    fn scatter_into_uninit<'b, T>(&self, recvbuf: &'b mut [MaybeUninit<T>]) -> &'b mut [T]
+    where
+        T: Equivalence,
+    {
+        let mut count: Count = 0;
+        self.broadcast_into(&mut count);
+        let len = count_len(count);
+        assert!(
+            recvbuf.len() >= len,
+            "Receive buffer is too small for the scattered elements."
+        );
+        self.scatter_into(&mut recvbuf[..len]);
+        unsafe { assume_init_prefix(recvbuf, len) }
+    }
+
     /// Scatter contents of a buffer on the root process to all processes.
     ///
     /// After the call completes each participating process will have received a part of the send
@@ -921,6 +1519,73 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Scatter contents of a slice on the root process into newly allocated `Vec`s on all
+    /// processes.
+    ///
+    /// The count of elements each process receives is broadcast by the root process first.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/scatter_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.6
+    fn scatter_vec<T>(&self) -> Vec<T>
+    where
+        T: Equivalence,
+    {
+        let mut count: Count = 0;
+        self.broadcast_into(&mut count);
+        assert!(
+            count >= 0,
+            "Send buffer of the root process cannot be split evenly among processes."
+        );
+        let mut res = uninit_vec::<T>(count_len(count));
+        self.scatter_into(&mut res[..]);
+        unsafe { assume_init_vec(res) }
+    }
+
+    /// Scatter contents of a slice on the root process into newly allocated `Vec`s on all
+    /// processes.
+    ///
+    /// `sendbuf` is split into as many parts of equal length as there are processes, so its length
+    /// must be a multiple of the size of the `Communicator`.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/scatter_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.6
+    fn scatter_vec_root<T>(&self, sendbuf: &[T]) -> Vec<T>
+    where
+        T: Equivalence,
+    {
+        assert!(is_local_root(self));
+        let size = target_len(self.as_communicator());
+        let len = sendbuf.len() / size;
+        let even = sendbuf.len() % size == 0;
+        // A negative count makes the other processes fail as well instead of waiting for the
+        // scatter
+        let mut count: Count = if even {
+            len.value_as()
+                .expect("Element count cannot be expressed as a Count.")
+        } else {
+            -1
+        };
+        self.broadcast_into(&mut count);
+        assert!(even, "Send buffer cannot be split evenly among processes.");
+        let mut res = uninit_vec::<T>(len);
+        self.scatter_into_root(sendbuf, &mut res[..]);
+        unsafe { assume_init_vec(res) }
+    }
+
     /// Scatter contents of a buffer on the root process to all processes.
     ///
     /// After the call completes each participating process will have received a part of the send
@@ -996,6 +1661,79 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Scatter contents of a slice on the root process into newly allocated `Vec`s on all
+    /// processes.
+    ///
+    /// The count of elements each process receives may vary and is scattered by the root process
+    /// first.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/scatter_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.6
+    fn scatter_varcount_vec<T>(&self) -> Vec<T>
+    where
+        T: Equivalence,
+    {
+        let mut count: Count = 0;
+        self.scatter_into(&mut count);
+        assert!(
+            count >= 0,
+            "Counts of the root process do not match its send buffer."
+        );
+        let mut res = uninit_vec::<T>(count_len(count));
+        self.scatter_varcount_into(&mut res[..]);
+        unsafe { assume_init_vec(res) }
+    }
+
+    /// Scatter contents of a slice on the root process into newly allocated `Vec`s on all
+    /// processes.
+    ///
+    /// `counts` holds the count of elements to send to each process, in order of rank; the
+    /// elements are taken from `sendbuf` consecutively.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/scatter_vec.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.6
+    fn scatter_varcount_vec_root<T>(&self, sendbuf: &[T], counts: &[Count]) -> Vec<T>
+    where
+        T: Equivalence,
+    {
+        assert!(is_local_root(self));
+        let size = target_len(self.as_communicator());
+        let valid = counts.len() == size
+            && counts.iter().all(|&count| count >= 0)
+            && total_count(counts) <= sendbuf.len();
+        let mut count: Count = 0;
+        if !valid {
+            // A negative count makes the other processes fail as well instead of waiting for the
+            // scatter
+            let invalid: Vec<Count> = vec![-1; size];
+            self.scatter_into_root(&invalid[..], &mut count);
+            panic!("Counts do not match the size of the communicator or the send buffer.");
+        }
+        self.scatter_into_root(counts, &mut count);
+        let displs = displacements(counts);
+
+        let mut res = uninit_vec::<T>(count_len(count));
+        {
+            let partition = Partition::new(sendbuf, counts, &displs[..]);
+            self.scatter_varcount_into_root(&partition, &mut res[..]);
+        }
+        unsafe { assume_init_vec(res) }
+    }
+
     /// Performs a global reduction under the operation `op` of the input data in `sendbuf` and
     /// stores the result on the `Root` process.
     ///
@@ -1096,6 +1834,25 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Initiate broadcast of a value from the `Root` process to all other processes.
+    ///
+    /// Unlike `immediate_broadcast_into`, the buffer is moved into the returned `OwnedRequest` and
+    /// handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.2
+    fn immediate_broadcast_into_owned<Buf>(&self, buf: Buf) -> OwnedRequest<Buf>
+    where
+        Buf: 'static + BufferMut,
+    {
+        unsafe { OwnedRequest::start(buf, |buf| self.immediate_broadcast_into(StaticScope, buf)) }
+    }
+
     /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
     ///
     /// This function must be called on all non-root processes.
@@ -1139,6 +1896,31 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
+    ///
+    /// Unlike `immediate_gather_into`, the buffer is moved into the returned `OwnedRequest` and
+    /// handed back when the request is completed.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.3
+    fn immediate_gather_into_owned<S>(&self, sendbuf: S) -> OwnedRequest<S>
+    where
+        S: 'static + Buffer,
+    {
+        unsafe {
+            OwnedRequest::start(sendbuf, |sendbuf| {
+                self.immediate_gather_into(StaticScope, &*sendbuf)
+            })
+        }
+    }
+
     /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
     ///
     /// This function must be called on the root processes.
@@ -1185,6 +1967,32 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
+    ///
+    /// Unlike `immediate_gather_into_root`, the buffers are moved into the returned `OwnedRequest`
+    /// and handed back when the request is completed.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.3
+    fn immediate_gather_into_root_owned<S, R>(&self, sendbuf: S, recvbuf: R) -> OwnedRequest<(S, R)>
+    where
+        S: 'static + Buffer,
+        R: 'static + BufferMut,
+    {
+        unsafe {
+            OwnedRequest::start((sendbuf, recvbuf), |(sendbuf, recvbuf)| {
+                self.immediate_gather_into_root(StaticScope, &*sendbuf, recvbuf)
+            })
+        }
+    }
+
     /// Initiate non-blocking gather of the contents of all `sendbuf`s on `Root` `&self`.
     ///
     /// This function must be called on all non-root processes.
@@ -1320,31 +2128,56 @@ pub trait Root: AsCommunicator {
 
     /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
     ///
-    /// This function must be called on the root processes.
+    /// Unlike `immediate_scatter_into`, the buffer is moved into the returned `OwnedRequest` and
+    /// handed back when the request is completed.
+    ///
+    /// This function must be called on all non-root processes.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate_scatter.rs`
+    /// See `examples/immediate_owned.rs`
     ///
     /// # Standard section(s)
     ///
     /// 5.12.4
-    fn immediate_scatter_into_root<'a, Sc, S: ?Sized, R: ?Sized>(
-        &self,
-        scope: Sc,
-        sendbuf: &'a S,
-        recvbuf: &'a mut R,
-    ) -> Request<'a, R, Sc>
+    fn immediate_scatter_into_owned<R>(&self, recvbuf: R) -> OwnedRequest<R>
     where
-        S: 'a + Buffer,
-        R: 'a + BufferMut,
-        Sc: Scope<'a>,
+        R: 'static + BufferMut,
     {
-        assert_eq!(self.as_communicator().rank(), self.root_rank());
         unsafe {
-            let sendcount = sendbuf.count() / self.as_communicator().target_size();
-            Request::from_raw(
-                with_uninitialized(|request| {
+            OwnedRequest::start(recvbuf, |recvbuf| {
+                self.immediate_scatter_into(StaticScope, recvbuf)
+            })
+        }
+    }
+
+    /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
+    ///
+    /// This function must be called on the root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_scatter.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.4
+    fn immediate_scatter_into_root<'a, Sc, S: ?Sized, R: ?Sized>(
+        &self,
+        scope: Sc,
+        sendbuf: &'a S,
+        recvbuf: &'a mut R,
+    ) -> Request<'a, R, Sc>
+    where
+        S: 'a + Buffer,
+        R: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        assert_eq!(self.as_communicator().rank(), self.root_rank());
+        unsafe {
+            let sendcount = sendbuf.count() / self.as_communicator().target_size();
+            Request::from_raw(
+                with_uninitialized(|request| {
                     ffi::MPI_Iscatter(
                         sendbuf.pointer(),
                         sendcount,
@@ -1364,6 +2197,36 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
+    ///
+    /// Unlike `immediate_scatter_into_root`, the buffers are moved into the returned `OwnedRequest`
+    /// and handed back when the request is completed.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.4
+    fn immediate_scatter_into_root_owned<S, R>(
+        &self,
+        sendbuf: S,
+        recvbuf: R,
+    ) -> OwnedRequest<(S, R)>
+    where
+        S: 'static + Buffer,
+        R: 'static + BufferMut,
+    {
+        unsafe {
+            OwnedRequest::start((sendbuf, recvbuf), |(sendbuf, recvbuf)| {
+                self.immediate_scatter_into_root(StaticScope, &*sendbuf, recvbuf)
+            })
+        }
+    }
+
     /// Initiate non-blocking scatter of the contents of `sendbuf` from `Root` `&self`.
     ///
     /// This function must be called on all non-root processes.
@@ -1499,6 +2362,33 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Initiates a non-blocking global reduction under the operation `op` of the input data in
+    /// `sendbuf` and stores the result on the `Root` process.
+    ///
+    /// Unlike `immediate_reduce_into`, the buffer is moved into the returned `OwnedRequest` and
+    /// handed back when the request is completed.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.7
+    fn immediate_reduce_into_owned<S, O>(&self, sendbuf: S, op: O) -> OwnedRequest<S>
+    where
+        S: 'static + Buffer,
+        O: 'static + Operation,
+    {
+        unsafe {
+            OwnedRequest::start(sendbuf, |sendbuf| {
+                self.immediate_reduce_into(StaticScope, &*sendbuf, op)
+            })
+        }
+    }
+
     /// Initiates a non-blocking global reduction under the operation `op` of the input data in
     /// `sendbuf` and stores the result on the `Root` process.
     ///
@@ -1546,69 +2436,64 @@ pub trait Root: AsCommunicator {
         }
     }
 
+    /// Initiates a non-blocking global reduction under the operation `op` of the input data in
+    /// `sendbuf` and stores the result on the `Root` process.
+    ///
+    /// Unlike `immediate_reduce_into_root`, the buffers are moved into the returned `OwnedRequest`
+    /// and handed back when the request is completed.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.12.7
+    fn immediate_reduce_into_root_owned<S, R, O>(
+        &self,
+        sendbuf: S,
+        recvbuf: R,
+        op: O,
+    ) -> OwnedRequest<(S, R)>
+    where
+        S: 'static + Buffer,
+        R: 'static + BufferMut,
+        O: 'static + Operation,
+    {
+        unsafe {
+            OwnedRequest::start((sendbuf, recvbuf), |(sendbuf, recvbuf)| {
+                self.immediate_reduce_into_root(StaticScope, &*sendbuf, recvbuf, op)
+            })
+        }
+    }
+
     /// Spawns child processes
     ///
     /// # Standard sections
     /// 10.3.2, see MPI_Comm_spawn
     fn spawn(&self, command: &Command, maxprocs: Rank) -> Result<InterCommunicator, MpiError> {
-        // Environment variables can be handled using the info key
-        assert_eq!(
-            command.get_envs().len(),
-            0,
-            "Support for environment variables not yet implemented"
-        );
-
-        // The Microsoft-MPI implementation treats the char* arguments as being
-        // encoded in utf8, and are internally converted to Windows wide
-        // characters. See ConvertArgs() using
-        // [`MultiByteToWideChar`](https://learn.microsoft.com/en-us/windows/win32/api/stringapiset/nf-stringapiset-multibytetowidechar)
-        // with `CP_UTF8` when called from MPIDI_Comm_spawn_multiple().
-        // https://github.com/microsoft/Microsoft-MPI/blob/7ff6bdcdb1d5dc7b791e47457ee2686cd6b3d355/src/mpi/msmpi/mpid/dynamic.cpp#L2074
-        //
-        // Since Windows wide-char strings are allowed to contain invalid
-        // UTF-16, such characters cannot be preserved. We'll choose lossy
-        // conversion, but returning an error is an option to consider.
-        let prog = CString::new(command.get_program().to_string_lossy().as_bytes())?;
-        let mut args: Vec<CString> = command
-            .get_args()
-            .map(|os| CString::new(os.to_string_lossy().as_bytes()))
-            .collect::<Result<Vec<CString>, NulError>>()?;
-        // We must retain args above so that the strings are not dropped while
-        // being used. An alternative that seems to be recommended any time the
-        // function takes mutable C strings is to use CString::into_raw to give
-        // ownership to the *mut c_char for the function call, then reclaim
-        // using CString::from_raw.
-        let mut argv: Vec<*mut c_char> = args
-            .iter_mut()
-            .map(|s| s.as_ptr() as *mut c_char)
-            .chain(std::iter::once(ptr::null_mut()))
-            .collect();
-
-        let mut result = unsafe { ffi::RSMPI_COMM_NULL };
-        let mut errcodes: Vec<c_int> =
-            vec![0; maxprocs.value_as().expect("maxprocs should be positive")];
+        unsafe { spawn_with_raw_info(self, command, maxprocs, ffi::RSMPI_INFO_NULL) }
+    }
 
-        unsafe {
-            ffi::MPI_Comm_spawn(
-                prog.as_ptr(),
-                argv.as_mut_ptr(),
-                maxprocs,
-                ffi::RSMPI_INFO_NULL,
-                self.root_rank(),
-                self.as_communicator().as_raw(),
-                &mut result,
-                errcodes.as_mut_ptr(),
-            );
-        }
-        let fails = errcodes
-            .into_iter()
-            .filter(|&c| c != ffi::MPI_SUCCESS as i32)
-            .count();
-        if fails > 0 {
-            Err(MpiError::Spawn(Rank::try_from(fails).unwrap(), maxprocs))
-        } else {
-            Ok(unsafe { InterCommunicator::from_raw(result) })
-        }
+    /// Spawns child processes, passing hints like `host` or `wdir` in `info`
+    ///
+    /// `info` is only significant at the root process.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/spawn.rs`
+    ///
+    /// # Standard sections
+    /// 10.3.2, 10.3.4, see MPI_Comm_spawn
+    fn spawn_with_info(
+        &self,
+        command: &Command,
+        maxprocs: Rank,
+        info: &Info,
+    ) -> Result<InterCommunicator, MpiError> {
+        unsafe { spawn_with_raw_info(self, command, maxprocs, info.as_raw()) }
     }
 
     /// Spawns child processes
@@ -1620,67 +2505,267 @@ pub trait Root: AsCommunicator {
         commands: &[Command],
         maxprocs: &[Rank],
     ) -> Result<InterCommunicator, MpiError> {
-        assert_eq!(commands.len(), maxprocs.len());
-
-        let progs = commands
-            .iter()
-            .map(|c| CString::new(c.get_program().to_string_lossy().as_bytes()))
-            .collect::<Result<Vec<CString>, NulError>>()?;
-        let mut progp: Vec<*mut c_char> = progs.iter().map(|p| p.as_ptr() as *mut c_char).collect();
-        let mut argss = commands
-            .iter()
-            .map(|c| {
-                c.get_args()
-                    .map(|os| CString::new(os.to_string_lossy().as_bytes()))
-                    .collect::<Result<Vec<CString>, NulError>>()
-            })
-            .collect::<Result<Vec<Vec<CString>>, NulError>>()?;
-        let mut argvs: Vec<Vec<*mut c_char>> = argss
-            .iter_mut()
-            .map(|args| {
-                args.iter_mut()
-                    .map(|a| a.as_ptr() as *mut c_char)
-                    .chain(std::iter::once(ptr::null_mut()))
+        let infos = vec![unsafe { ffi::RSMPI_INFO_NULL }; commands.len()];
+        unsafe { spawn_multiple_with_raw_infos(self, commands, maxprocs, &infos) }
+    }
+
+    /// Spawns child processes, passing hints for `commands[i]` in `infos[i]`
+    ///
+    /// `infos` is only significant at the root process.
+    ///
+    /// # Standard sections
+    /// 10.3.3, 10.3.4, see MPI_Comm_spawn_multiple
+    fn spawn_multiple_with_info(
+        &self,
+        commands: &[Command],
+        maxprocs: &[Rank],
+        infos: &[Info],
+    ) -> Result<InterCommunicator, MpiError> {
+        assert_eq!(commands.len(), infos.len());
+        let infos: Vec<_> = infos.iter().map(|info| info.as_raw()).collect();
+        unsafe { spawn_multiple_with_raw_infos(self, commands, maxprocs, &infos) }
+    }
+
+    /// Broadcast a value encoded in `format` from the `Root` process to all other processes.
+    ///
+    /// On the root process, `value` is encoded and sent.  On all other processes, `value` is
+    /// replaced by the decoded value.  If encoding fails on the root process, the root process
+    /// returns `CollectiveError::Format` and all other processes return `CollectiveError::Remote`.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/serialized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.4
+    #[cfg(feature = "serde")]
+    fn broadcast_serialized<F, T>(
+        &self,
+        format: &F,
+        value: &mut T,
+    ) -> Result<(), CollectiveError<F::Error>>
+    where
+        F: WireFormat,
+        T: Serialize + DeserializeOwned,
+    {
+        if is_local_root(self) {
+            let encoded = format.serialize(value);
+            let mut success = encoded.is_ok();
+            self.broadcast_into(&mut success);
+            let mut bytes = encoded.map_err(CollectiveError::Format)?;
+            self.broadcast_vec(&mut bytes);
+            Ok(())
+        } else {
+            let mut success = false;
+            self.broadcast_into(&mut success);
+            if !success {
+                return Err(CollectiveError::Remote(self.root_rank()));
+            }
+            let mut bytes = Vec::new();
+            self.broadcast_vec(&mut bytes);
+            *value = format
+                .deserialize(&bytes)
+                .map_err(CollectiveError::Format)?;
+            Ok(())
+        }
+    }
+
+    /// Gather values encoded in `format` from all processes on the `Root` process.
+    ///
+    /// Returns the values of all processes ordered by rank on the root process and `None` on all
+    /// other processes.  This function must be called on all processes.
+    ///
+    /// A process on which encoding fails returns `CollectiveError::Format`.  If encoding fails on
+    /// any other process, the root process returns `CollectiveError::Remote` with the lowest such
+    /// rank.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/serialized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.5
+    #[cfg(feature = "serde")]
+    fn gather_serialized<F, T>(
+        &self,
+        format: &F,
+        value: &T,
+    ) -> Result<Option<Vec<T>>, CollectiveError<F::Error>>
+    where
+        F: WireFormat,
+        T: Serialize + DeserializeOwned,
+    {
+        let (bytes, error) = match format.serialize(value) {
+            Ok(bytes) => (bytes, None),
+            Err(error) => (Vec::new(), Some(error)),
+        };
+        let successes = self.gather_vec(&[error.is_none()]);
+        let gathered = self.gather_varcount_vec(&bytes[..]);
+        if let Some(error) = error {
+            return Err(CollectiveError::Format(error));
+        }
+        if let Some(rank) = successes.and_then(|successes| successes.iter().position(|&s| !s)) {
+            return Err(CollectiveError::Remote(Rank::try_from(rank).unwrap()));
+        }
+        gathered
+            .map(|(bytes, counts)| {
+                let mut offset = 0;
+                counts
+                    .iter()
+                    .map(|&count| {
+                        let len = count_len(count);
+                        let value = format.deserialize(&bytes[offset..offset + len]);
+                        offset += len;
+                        value.map_err(CollectiveError::Format)
+                    })
                     .collect()
             })
-            .collect();
+            .transpose()
+    }
+}
 
-        let mut argvv: Vec<*mut *mut c_char> =
-            argvs.iter_mut().map(|argv| argv.as_mut_ptr()).collect();
+/// Spawns child processes with the raw `info` handle, see `Root::spawn_with_info()`.
+///
+/// # Safety
+/// `info` must be a valid info handle or `MPI_INFO_NULL`.
+unsafe fn spawn_with_raw_info<R: Root + ?Sized>(
+    root: &R,
+    command: &Command,
+    maxprocs: Rank,
+    info: MPI_Info,
+) -> Result<InterCommunicator, MpiError> {
+    // Environment variables can be handled using the info key
+    assert_eq!(
+        command.get_envs().len(),
+        0,
+        "Support for environment variables not yet implemented"
+    );
+
+    // The Microsoft-MPI implementation treats the char* arguments as being
+    // encoded in utf8, and are internally converted to Windows wide
+    // characters. See ConvertArgs() using
+    // [`MultiByteToWideChar`](https://learn.microsoft.com/en-us/windows/win32/api/stringapiset/nf-stringapiset-multibytetowidechar)
+    // with `CP_UTF8` when called from MPIDI_Comm_spawn_multiple().
+    // https://github.com/microsoft/Microsoft-MPI/blob/7ff6bdcdb1d5dc7b791e47457ee2686cd6b3d355/src/mpi/msmpi/mpid/dynamic.cpp#L2074
+    //
+    // Since Windows wide-char strings are allowed to contain invalid
+    // UTF-16, such characters cannot be preserved. We'll choose lossy
+    // conversion, but returning an error is an option to consider.
+    let prog = CString::new(command.get_program().to_string_lossy().as_bytes())?;
+    let mut args: Vec<CString> = command
+        .get_args()
+        .map(|os| CString::new(os.to_string_lossy().as_bytes()))
+        .collect::<Result<Vec<CString>, NulError>>()?;
+    // We must retain args above so that the strings are not dropped while
+    // being used. An alternative that seems to be recommended any time the
+    // function takes mutable C strings is to use CString::into_raw to give
+    // ownership to the *mut c_char for the function call, then reclaim
+    // using CString::from_raw.
+    let mut argv: Vec<*mut c_char> = args
+        .iter_mut()
+        .map(|s| s.as_ptr() as *mut c_char)
+        .chain(std::iter::once(ptr::null_mut()))
+        .collect();
+
+    let mut result = unsafe { ffi::RSMPI_COMM_NULL };
+    let mut errcodes: Vec<c_int> =
+        vec![0; maxprocs.value_as().expect("maxprocs should be positive")];
 
-        let infos: Vec<_> = (0..commands.len())
-            .map(|_| unsafe { ffi::RSMPI_INFO_NULL })
-            .collect();
+    unsafe {
+        ffi::MPI_Comm_spawn(
+            prog.as_ptr(),
+            argv.as_mut_ptr(),
+            maxprocs,
+            info,
+            root.root_rank(),
+            root.as_communicator().as_raw(),
+            &mut result,
+            errcodes.as_mut_ptr(),
+        );
+    }
+    let fails = errcodes
+        .into_iter()
+        .filter(|&c| c != ffi::MPI_SUCCESS as i32)
+        .count();
+    if fails > 0 {
+        Err(MpiError::Spawn(Rank::try_from(fails).unwrap(), maxprocs))
+    } else {
+        Ok(unsafe { InterCommunicator::from_raw(result) })
+    }
+}
 
-        let mut result = unsafe { ffi::RSMPI_COMM_NULL };
-        let sum_maxprocs: Rank = maxprocs.iter().sum();
-        let mut errcodes = vec![0; usize::try_from(sum_maxprocs).unwrap()];
+/// Spawns child processes with the raw `infos` handles, see `Root::spawn_multiple_with_info()`.
+///
+/// # Safety
+/// Each of `infos` must be a valid info handle or `MPI_INFO_NULL`.
+unsafe fn spawn_multiple_with_raw_infos<R: Root + ?Sized>(
+    root: &R,
+    commands: &[Command],
+    maxprocs: &[Rank],
+    infos: &[MPI_Info],
+) -> Result<InterCommunicator, MpiError> {
+    assert_eq!(commands.len(), maxprocs.len());
+    assert_eq!(commands.len(), infos.len());
+
+    let progs = commands
+        .iter()
+        .map(|c| CString::new(c.get_program().to_string_lossy().as_bytes()))
+        .collect::<Result<Vec<CString>, NulError>>()?;
+    let mut progp: Vec<*mut c_char> = progs.iter().map(|p| p.as_ptr() as *mut c_char).collect();
+    let mut argss = commands
+        .iter()
+        .map(|c| {
+            c.get_args()
+                .map(|os| CString::new(os.to_string_lossy().as_bytes()))
+                .collect::<Result<Vec<CString>, NulError>>()
+        })
+        .collect::<Result<Vec<Vec<CString>>, NulError>>()?;
+    let mut argvs: Vec<Vec<*mut c_char>> = argss
+        .iter_mut()
+        .map(|args| {
+            args.iter_mut()
+                .map(|a| a.as_ptr() as *mut c_char)
+                .chain(std::iter::once(ptr::null_mut()))
+                .collect()
+        })
+        .collect();
+
+    let mut argvv: Vec<*mut *mut c_char> = argvs.iter_mut().map(|argv| argv.as_mut_ptr()).collect();
+
+    let mut result = unsafe { ffi::RSMPI_COMM_NULL };
+    let sum_maxprocs: Rank = maxprocs.iter().sum();
+    let mut errcodes = vec![0; usize::try_from(sum_maxprocs).unwrap()];
 
-        unsafe {
-            ffi::MPI_Comm_spawn_multiple(
-                progs.len().value_as().unwrap(),
-                progp.as_mut_ptr(),
-                argvv.as_mut_ptr(),
-                maxprocs.as_ptr(),
-                infos.as_ptr(),
-                self.root_rank(),
-                self.as_communicator().as_raw(),
-                &mut result,
-                errcodes.as_mut_ptr(),
-            );
-        }
-        let fails = errcodes
-            .into_iter()
-            .filter(|&c| c != ffi::MPI_SUCCESS as i32)
-            .count();
-        if fails > 0 {
-            Err(MpiError::Spawn(
-                Rank::try_from(fails).unwrap(),
-                sum_maxprocs,
-            ))
-        } else {
-            Ok(unsafe { InterCommunicator::from_raw(result) })
-        }
+    unsafe {
+        ffi::MPI_Comm_spawn_multiple(
+            progs.len().value_as().unwrap(),
+            progp.as_mut_ptr(),
+            argvv.as_mut_ptr(),
+            maxprocs.as_ptr(),
+            infos.as_ptr(),
+            root.root_rank(),
+            root.as_communicator().as_raw(),
+            &mut result,
+            errcodes.as_mut_ptr(),
+        );
+    }
+    let fails = errcodes
+        .into_iter()
+        .filter(|&c| c != ffi::MPI_SUCCESS as i32)
+        .count();
+    if fails > 0 {
+        Err(MpiError::Spawn(
+            Rank::try_from(fails).unwrap(),
+            sum_maxprocs,
+        ))
+    } else {
+        Ok(unsafe { InterCommunicator::from_raw(result) })
     }
 }
 
@@ -1709,13 +2794,16 @@ impl<'a, T: 'a + Operation> Operation for &'a T {}
 
 /// A built-in operation like `MPI_SUM`
 ///
+/// `min_loc` and `max_loc` operate on value-index pairs like `datatype::ValueIndex`, while
+/// `replace` and `no_op` are only meaningful in one-sided accumulate operations.
+///
 /// # Examples
 ///
-/// See `examples/reduce.rs`
+/// See `examples/reduce.rs` and `examples/reduce_minloc.rs`
 ///
 /// # Standard section(s)
 ///
-/// 5.9.2
+/// 5.9.2, 5.9.4, 12.3.4
 #[derive(Copy, Clone)]
 pub struct SystemOperation(MPI_Op);
 
@@ -1739,7 +2827,11 @@ impl SystemOperation {
         logical_or => ffi::RSMPI_LOR,
         bitwise_or => ffi::RSMPI_BOR,
         logical_xor => ffi::RSMPI_LXOR,
-        bitwise_xor => ffi::RSMPI_BXOR
+        bitwise_xor => ffi::RSMPI_BXOR,
+        min_loc => ffi::RSMPI_MINLOC,
+        max_loc => ffi::RSMPI_MAXLOC,
+        replace => ffi::RSMPI_REPLACE,
+        no_op => ffi::RSMPI_NO_OP
     }
 }
 
@@ -1937,8 +3029,7 @@ impl<'a> UserOperation<'a> {
 /// signatures of user functions defined in the MPI C bindings, `UnsafeUserFunction`.
 ///
 /// The recommended way to create user-defined operations is through the safer `UserOperation`
-/// type. This type can be used as a work-around in situations where the `libffi` dependency is not
-/// available.
+/// or `TypedUserOperation` types.
 pub struct UnsafeUserOperation {
     op: MPI_Op,
 }
@@ -2032,6 +3123,282 @@ impl UnsafeUserOperation {
     }
 }
 
+/// A reduction function over a single element type, used to define a `TypedUserOperation`.
+///
+/// Unlike the closures of `UserOperation`, implementations of this trait have no state, which
+/// allows the `extern "C"` callback handed to MPI to be generated at compile time. Thus, it does
+/// not depend on `libffi`.
+///
+/// # Examples
+///
+/// See `examples/reduce_typed.rs`
+pub trait UserReduction {
+    /// The type of the elements this reduction operates on
+    type Item: Equivalence;
+
+    /// Whether the reduction is commutative in addition to being associative.
+    ///
+    /// Declaring a reduction commutative may yield performance benefits.
+    const COMMUTATIVE: bool = false;
+
+    /// Combine the elements of `invec` into `inoutvec`.
+    ///
+    /// Shall set `inoutvec[i]` to the value of `f(invec[i], inoutvec[i])`, where `f` is a binary
+    /// associative operation. Both slices have the same length.
+    fn combine(invec: &[Self::Item], inoutvec: &mut [Self::Item]);
+}
+
+/// A user-defined operation backed by a `UserReduction`.
+///
+/// The operation is applied through its own reduction methods, which only accept buffers of
+/// `R::Item`. It does not implement `Operation`, since that would allow using it with buffers of
+/// any other type.
+///
+/// **Note:** If `R::combine` panics, the entire program will abort.
+///
+/// # Examples
+///
+/// See `examples/reduce_typed.rs`
+///
+/// # Standard section(s)
+///
+/// 5.9.5
+pub struct TypedUserOperation<R> {
+    op: MPI_Op,
+    phantom: PhantomData<fn() -> R>,
+}
+
+impl<R> fmt::Debug for TypedUserOperation<R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("TypedUserOperation").field(&self.op).finish()
+    }
+}
+
+impl<R> Drop for TypedUserOperation<R> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Op_free(&mut self.op);
+        }
+    }
+}
+
+unsafe impl<R> AsRaw for TypedUserOperation<R> {
+    type Raw = MPI_Op;
+    fn as_raw(&self) -> Self::Raw {
+        self.op
+    }
+}
+
+/// The operation of a `TypedUserOperation`, once the item type of the buffers has been checked
+struct CheckedOperation(MPI_Op);
+
+unsafe impl AsRaw for CheckedOperation {
+    type Raw = MPI_Op;
+    fn as_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+impl Operation for CheckedOperation {}
+
+impl<R: UserReduction> TypedUserOperation<R> {
+    /// Create the operation, registering the callback generated for `R` with MPI.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.5
+    pub fn new() -> Self {
+        TypedUserOperation {
+            op: unsafe {
+                with_uninitialized(|op| {
+                    ffi::MPI_Op_create(Some(typed_user_function::<R>), R::COMMUTATIVE as _, op)
+                })
+                .1
+            },
+            phantom: PhantomData,
+        }
+    }
+
+    fn checked(&self) -> CheckedOperation {
+        CheckedOperation(self.op)
+    }
+
+    /// Performs a global reduction of `sendbuf` and stores the result in `recvbuf` on all
+    /// processes of `comm`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/reduce_typed.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.6
+    pub fn all_reduce_into<C>(&self, comm: &C, sendbuf: &[R::Item], recvbuf: &mut [R::Item])
+    where
+        C: CommunicatorCollectives + ?Sized,
+    {
+        comm.all_reduce_into(sendbuf, recvbuf, self.checked());
+    }
+
+    /// Performs a global reduction of `sendbuf` and stores the result on the `root` process.
+    ///
+    /// This function must be called on all non-root processes.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.1
+    pub fn reduce_into<P: Root>(&self, root: &P, sendbuf: &[R::Item]) {
+        root.reduce_into(sendbuf, self.checked());
+    }
+
+    /// Performs a global reduction of `sendbuf` and stores the result in `recvbuf` on the `root`
+    /// process.
+    ///
+    /// This function must be called on the root process.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.1
+    pub fn reduce_into_root<P: Root>(
+        &self,
+        root: &P,
+        sendbuf: &[R::Item],
+        recvbuf: &mut [R::Item],
+    ) {
+        root.reduce_into_root(sendbuf, recvbuf, self.checked());
+    }
+
+    /// Performs a global inclusive prefix reduction of `sendbuf` into `recvbuf`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.11.1
+    pub fn scan_into<C>(&self, comm: &C, sendbuf: &[R::Item], recvbuf: &mut [R::Item])
+    where
+        C: CommunicatorCollectives + ?Sized,
+    {
+        comm.scan_into(sendbuf, recvbuf, self.checked());
+    }
+
+    /// Performs a global exclusive prefix reduction of `sendbuf` into `recvbuf`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.11.2
+    pub fn exclusive_scan_into<C>(&self, comm: &C, sendbuf: &[R::Item], recvbuf: &mut [R::Item])
+    where
+        C: CommunicatorCollectives + ?Sized,
+    {
+        comm.exclusive_scan_into(sendbuf, recvbuf, self.checked());
+    }
+
+    /// Performs a local reduction of `inbuf` into `inoutbuf`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/reduce_typed.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 5.9.7
+    pub fn reduce_local_into(&self, inbuf: &[R::Item], inoutbuf: &mut [R::Item]) {
+        reduce_local_into(inbuf, inoutbuf, self.checked());
+    }
+}
+
+impl<R: UserReduction> Default for TypedUserOperation<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// The item type of the buffers has been checked by the methods of `TypedUserOperation`, so the
+// datatype does not have to be inspected.
+unsafe fn typed_user_reduce<R: UserReduction>(
+    invec: *mut c_void,
+    inoutvec: *mut c_void,
+    len: *mut c_int,
+) {
+    let len: usize = (*len).value_as().unwrap_or(0);
+    if len == 0 {
+        // precautionary measure: the pointers may be null
+        return;
+    }
+    let invec = slice::from_raw_parts(invec as *const R::Item, len);
+    let inoutvec = slice::from_raw_parts_mut(inoutvec as *mut R::Item, len);
+
+    if panic::catch_unwind(AssertUnwindSafe(|| R::combine(invec, inoutvec))).is_err() {
+        process::abort();
+    }
+}
+
+#[cfg(not(all(msmpi, target_arch = "x86")))]
+unsafe extern "C" fn typed_user_function<R: UserReduction>(
+    invec: *mut c_void,
+    inoutvec: *mut c_void,
+    len: *mut c_int,
+    _datatype: *mut ffi::MPI_Datatype,
+) {
+    typed_user_reduce::<R>(invec, inoutvec, len)
+}
+
+// MS-MPI uses "stdcall" calling convention on 32-bit x86
+#[cfg(all(msmpi, target_arch = "x86"))]
+unsafe extern "stdcall" fn typed_user_function<R: UserReduction>(
+    invec: *mut c_void,
+    inoutvec: *mut c_void,
+    len: *mut c_int,
+    _datatype: *mut ffi::MPI_Datatype,
+) {
+    typed_user_reduce::<R>(invec, inoutvec, len)
+}
+
+/// Whether the calling process is the root of `root`, for the helpers that choose between the root
+/// and non-root side of a collective themselves.
+///
+/// On an inter-communicator, the root group passes `MPI_ROOT` or `MPI_PROC_NULL` instead of a
+/// rank, which a `Root` cannot express. Since every process of an inter-communicator fails the
+/// check, none of them is left waiting for the others.
+fn is_local_root<P: Root + ?Sized>(root: &P) -> bool {
+    let comm = root.as_communicator();
+    assert!(
+        !comm.test_inter(),
+        "Choosing the root side of a collective is only supported on intra-communicators."
+    );
+    comm.rank() == root.root_rank()
+}
+
+/// Size of the remote group of `comm` as a `usize`, i.e. the count of partitions to receive
+fn target_len<C: Communicator + ?Sized>(comm: &C) -> usize {
+    comm.target_size()
+        .value_as()
+        .expect("Communicator size cannot be expressed as a usize.")
+}
+
+/// Displacements of consecutively stored partitions with the given `counts`
+fn displacements(counts: &[Count]) -> Vec<Count> {
+    counts
+        .iter()
+        .scan(0, |acc, &count| {
+            let displ = *acc;
+            *acc += count;
+            Some(displ)
+        })
+        .collect()
+}
+
+/// A count of elements as a `usize`, e.g. to allocate a buffer
+fn count_len(count: Count) -> usize {
+    count
+        .value_as()
+        .expect("Element count cannot be expressed as a usize.")
+}
+
+/// Total count of elements in all partitions
+fn total_count(counts: &[Count]) -> usize {
+    count_len(counts.iter().sum())
+}
+
 /// Perform a local reduction.
 ///
 /// # Examples
@@ -67,7 +67,8 @@
 
 use std::borrow::Borrow;
 use std::marker::PhantomData;
-use std::os::raw::c_void;
+use std::mem::MaybeUninit;
+use std::os::raw::{c_int, c_void};
 use std::{mem, slice};
 
 use conv::ConvUtil;
@@ -213,6 +214,40 @@ equivalent_system_datatype!(usize, ffi::RSMPI_UINT64_T);
 #[cfg(target_pointer_width = "64")]
 equivalent_system_datatype!(isize, ffi::RSMPI_INT64_T);
 
+/// A value paired with an index, as used by the `MPI_MINLOC` and `MPI_MAXLOC` reductions
+///
+/// The index is typically the rank of the process contributing the value, so that a `min_loc`
+/// reduction yields both the global minimum and the rank that holds it. Equivalent to the MPI
+/// pair types `MPI_FLOAT_INT`, `MPI_DOUBLE_INT`, `MPI_SHORT_INT` and `MPI_2INT`.
+///
+/// # Examples
+///
+/// See `examples/reduce_minloc.rs`
+///
+/// # Standard section(s)
+///
+/// 5.9.4
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct ValueIndex<T> {
+    /// The value being compared
+    pub value: T,
+    /// The index associated with the value, e.g. a rank
+    pub index: c_int,
+}
+
+impl<T> ValueIndex<T> {
+    /// Pair `value` with `index`
+    pub fn new(value: T, index: c_int) -> Self {
+        ValueIndex { value, index }
+    }
+}
+
+equivalent_system_datatype!(ValueIndex<f32>, ffi::RSMPI_FLOAT_INT);
+equivalent_system_datatype!(ValueIndex<f64>, ffi::RSMPI_DOUBLE_INT);
+equivalent_system_datatype!(ValueIndex<i16>, ffi::RSMPI_SHORT_INT);
+equivalent_system_datatype!(ValueIndex<i32>, ffi::RSMPI_2INT);
+
 #[cfg(feature = "complex")]
 /// Implement direct equivalence for complex types
 pub mod complex_datatype {
@@ -222,6 +257,29 @@ pub mod complex_datatype {
     equivalent_system_datatype!(Complex64, ffi::RSMPI_DOUBLE_COMPLEX);
 }
 
+/// Allocate a receive buffer of `len` uninitialized elements
+pub(crate) fn uninit_vec<T>(len: usize) -> Vec<MaybeUninit<T>> {
+    (0..len).map(|_| MaybeUninit::uninit()).collect()
+}
+
+/// Convert a receive buffer that has been completely filled in by MPI
+///
+/// # Safety
+/// All elements of `vec` must have been initialized.
+pub(crate) unsafe fn assume_init_vec<T>(vec: Vec<MaybeUninit<T>>) -> Vec<T> {
+    let mut vec = mem::ManuallyDrop::new(vec);
+    Vec::from_raw_parts(vec.as_mut_ptr() as *mut T, vec.len(), vec.capacity())
+}
+
+/// Convert the first `len` elements of a receive buffer that have been filled in by MPI
+///
+/// # Safety
+/// The first `len` elements of `buf` must have been initialized.
+pub(crate) unsafe fn assume_init_prefix<T>(buf: &mut [MaybeUninit<T>], len: usize) -> &mut [T] {
+    let prefix = &mut buf[..len];
+    slice::from_raw_parts_mut(prefix.as_mut_ptr() as *mut T, len)
+}
+
 /// A user defined MPI datatype
 ///
 /// # Standard section(s)
@@ -807,6 +865,26 @@ where
     }
 }
 
+unsafe impl<T> AsDatatype for Box<[T]>
+where
+    T: Equivalence,
+{
+    type Out = <T as Equivalence>::Out;
+    fn as_datatype(&self) -> Self::Out {
+        <T as Equivalence>::equivalent_datatype()
+    }
+}
+
+unsafe impl<T> AsDatatype for [MaybeUninit<T>]
+where
+    T: Equivalence,
+{
+    type Out = <T as Equivalence>::Out;
+    fn as_datatype(&self) -> Self::Out {
+        <T as Equivalence>::equivalent_datatype()
+    }
+}
+
 unsafe impl<T, const D: usize> AsDatatype for [T; D]
 where
     T: Equivalence,
@@ -909,6 +987,28 @@ where
     }
 }
 
+unsafe impl<T> Collection for Box<[T]>
+where
+    T: Equivalence,
+{
+    fn count(&self) -> Count {
+        self.len()
+            .value_as()
+            .expect("Length of slice cannot be expressed as an MPI Count.")
+    }
+}
+
+unsafe impl<T> Collection for [MaybeUninit<T>]
+where
+    T: Equivalence,
+{
+    fn count(&self) -> Count {
+        self.len()
+            .value_as()
+            .expect("Length of slice cannot be expressed as an MPI Count.")
+    }
+}
+
 unsafe impl<T, const D: usize> Collection for [T; D]
 where
     T: Equivalence,
@@ -954,6 +1054,15 @@ where
     }
 }
 
+unsafe impl<T> Pointer for Box<[T]>
+where
+    T: Equivalence,
+{
+    fn pointer(&self) -> *const c_void {
+        self.as_ptr() as _
+    }
+}
+
 unsafe impl<T, const D: usize> Pointer for [T; D]
 where
     T: Equivalence,
@@ -997,6 +1106,24 @@ where
     }
 }
 
+unsafe impl<T> PointerMut for Box<[T]>
+where
+    T: Equivalence,
+{
+    fn pointer_mut(&mut self) -> *mut c_void {
+        self.as_mut_ptr() as _
+    }
+}
+
+unsafe impl<T> PointerMut for [MaybeUninit<T>]
+where
+    T: Equivalence,
+{
+    fn pointer_mut(&mut self) -> *mut c_void {
+        self.as_mut_ptr() as _
+    }
+}
+
 unsafe impl<T, const D: usize> PointerMut for [T; D]
 where
     T: Equivalence,
@@ -1012,6 +1139,7 @@ pub unsafe trait Buffer: Pointer + Collection + AsDatatype {}
 unsafe impl<T> Buffer for T where T: Equivalence {}
 unsafe impl<T> Buffer for [T] where T: Equivalence {}
 unsafe impl<T> Buffer for Vec<T> where T: Equivalence {}
+unsafe impl<T> Buffer for Box<[T]> where T: Equivalence {}
 unsafe impl<T, const D: usize> Buffer for [T; D] where T: Equivalence {}
 
 /// A mutable buffer is a region in memory that starts at `pointer_mut()` and contains `count()`
@@ -1020,6 +1148,8 @@ pub unsafe trait BufferMut: PointerMut + Collection + AsDatatype {}
 unsafe impl<T> BufferMut for T where T: Equivalence {}
 unsafe impl<T> BufferMut for [T] where T: Equivalence {}
 unsafe impl<T> BufferMut for Vec<T> where T: Equivalence {}
+unsafe impl<T> BufferMut for Box<[T]> where T: Equivalence {}
+unsafe impl<T> BufferMut for [MaybeUninit<T>] where T: Equivalence {}
 unsafe impl<T, const D: usize> BufferMut for [T; D] where T: Equivalence {}
 
 /// An immutable dynamically-typed buffer.
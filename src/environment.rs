@@ -4,44 +4,84 @@
 //!
 //! # Unfinished features
 //!
-//! - **8.1.2**: `MPI_TAG_UB`, ...
-//! - **8.2**: Memory allocation
-//! - **8.3, 8.4, and 8.5**: Error handling
+//! - **8.3, 8.4, and 8.5**: Error handling, see the `error_handling` module for what is implemented
 
 use std::{
+    cell::Cell,
     cmp::Ordering,
+    fmt, mem,
     os::raw::{c_char, c_double, c_int, c_void},
-    ptr,
+    panic::{self, AssertUnwindSafe},
+    process, ptr,
     string::FromUtf8Error,
-    sync::RwLock,
+    sync::{
+        atomic::{self, AtomicBool, AtomicI32},
+        Mutex, MutexGuard, Once, RwLock,
+    },
     thread::{self, ThreadId},
 };
 
 use conv::ConvUtil;
 use once_cell::sync::Lazy;
 
-use crate::{attribute::AppNum, ffi};
-use crate::{attribute::UniverseSize, traits::FromRaw};
+use crate::attribute::{AppNum, Host, IoRank, TagUpperBound, UniverseSize, WtimeIsGlobal};
+use crate::{ffi, traits::FromRaw, Error, Tag};
 use crate::{
     topology::traits::AnyCommunicator,
-    topology::{Communicator, InterCommunicator, SimpleCommunicator},
+    topology::{Communicator, InterCommunicator, Rank, SimpleCommunicator},
     traits::AsRaw,
 };
 use crate::{with_uninitialized, with_uninitialized2};
 
 /// Internal data structure used to uphold certain MPI invariants.
-/// State is currently only used with the derive feature.
+/// State is used with the derive feature and by the checks of `Universe::set_thread_checks()`.
 pub(crate) struct UniverseState {
-    #[allow(unused)]
     pub main_thread: ThreadId,
+    pub threading: Threading,
 }
 
 pub(crate) static UNIVERSE_STATE: Lazy<RwLock<Option<UniverseState>>> =
     Lazy::new(|| RwLock::new(None));
 
+type FinalizeHook = Box<dyn FnOnce() + Send>;
+
+/// Whether `check_thread()` compares the calling thread with `UniverseState`
+static THREAD_CHECKS: AtomicBool = AtomicBool::new(false);
+
+/// Whether a panic aborts all processes, see `Universe::set_abort_on_panic()`
+static ABORT_ON_PANIC: AtomicBool = AtomicBool::new(false);
+
+/// The error code passed to `MPI_Abort` when a panic aborts all processes
+static PANIC_ERROR_CODE: AtomicI32 = AtomicI32::new(1);
+
+/// Installs the panic hook on the first call of `Universe::set_abort_on_panic()`
+static PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    /// Number of enclosing `defer_abort_on_panic()` calls on this thread
+    static DEFERRED_ABORTS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Hooks registered with `at_finalize()`
+static FINALIZE_HOOKS: Lazy<Mutex<FinalizeHooks>> = Lazy::new(|| {
+    Mutex::new(FinalizeHooks {
+        key: None,
+        hooks: Vec::new(),
+    })
+});
+
+/// The hooks registered with `at_finalize()` and the key of the `MPI_COMM_SELF` attribute whose
+/// deletion runs them
+struct FinalizeHooks {
+    key: Option<c_int>,
+    /// In the order of registration
+    hooks: Vec<FinalizeHook>,
+}
+
 /// Global context
 pub struct Universe {
     buffer: Option<Vec<u8>>,
+    finalize: bool,
 }
 
 impl Universe {
@@ -82,12 +122,78 @@ impl Universe {
         self.world().get_attr::<AppNum>().map(isize::from)
     }
 
+    /// The largest tag value that can be used, at least 32767
+    ///
+    /// # Examples
+    /// See `examples/env_inq.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.1.2
+    pub fn tag_ub(&self) -> Tag {
+        self.world()
+            .get_attr::<TagUpperBound>()
+            .map(Tag::from)
+            .expect("MPI_TAG_UB is not set on MPI_COMM_WORLD")
+    }
+
+    /// The rank of the host process in the world communicator, if there is one
+    ///
+    /// # Examples
+    /// See `examples/env_inq.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.1.2
+    pub fn host(&self) -> Option<Rank> {
+        self.world()
+            .get_attr::<Host>()
+            .map(Rank::from)
+            .filter(|&host| host != unsafe { ffi::RSMPI_PROC_NULL })
+    }
+
+    /// Which processes of the world communicator can perform I/O with the language's standard
+    /// facilities
+    ///
+    /// # Examples
+    /// See `examples/env_inq.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.1.2
+    pub fn io_process(&self) -> IoProcess {
+        let io = self.world().get_attr::<IoRank>().map(Rank::from);
+        match io {
+            Some(rank) if rank == unsafe { ffi::RSMPI_ANY_SOURCE } => IoProcess::Any,
+            Some(rank) if rank != unsafe { ffi::RSMPI_PROC_NULL } => IoProcess::Rank(rank),
+            _ => IoProcess::None,
+        }
+    }
+
+    /// Whether the clocks of all processes are synchronized, so `time()` returns comparable values
+    /// on all processes
+    ///
+    /// # Examples
+    /// See `examples/env_inq.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.1.2
+    pub fn wtime_is_global(&self) -> bool {
+        self.world()
+            .get_attr::<WtimeIsGlobal>()
+            .is_some_and(bool::from)
+    }
+
     /// The size in bytes of the buffer used for buffered communication.
     pub fn buffer_size(&self) -> usize {
         self.buffer.as_ref().map_or(0, Vec::len)
     }
 
     /// Set the size in bytes of the buffer used for buffered communication.
+    ///
+    /// The size needed for a set of messages can be computed with
+    /// [`BufferedSendPlan`](../point_to_point/struct.BufferedSendPlan.html).
     pub fn set_buffer_size(&mut self, size: usize) {
         self.detach_buffer();
 
@@ -150,6 +256,58 @@ impl Universe {
         }
     }
 
+    /// Enable or disable checking that MPI is only used as allowed by the level of multithreading
+    /// support.
+    ///
+    /// With checks enabled, using a communicator or completing a request panics if MPI was
+    /// initialized with `Threading::Single` or `Threading::Funneled` and the calling thread is not
+    /// the thread that initialized MPI.  Concurrent calls under `Threading::Serialized` are not
+    /// detected.  Checks are disabled by default, since they take a lock on every use.
+    ///
+    /// # Examples
+    /// See `examples/thread_checks.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.4.3
+    pub fn set_thread_checks(&self, enabled: bool) {
+        THREAD_CHECKS.store(enabled, atomic::Ordering::Release);
+    }
+
+    /// Abort all processes with `error_code` when any thread of this process panics, or restore
+    /// the default panic behavior with `None`.
+    ///
+    /// Otherwise, a panic on one process usually leaves the other processes waiting in
+    /// communication with it forever.  The panic message is printed with the rank in
+    /// `MPI_COMM_WORLD` as prefix and then `MPI_Abort` is called on `MPI_COMM_WORLD`.  This is
+    /// typically set right after `initialize()`.
+    ///
+    /// Outstanding requests are not completed before aborting, unless the panic happens inside
+    /// `defer_abort_on_panic()`, which waits for the requests of scopes that are unwound.
+    ///
+    /// # Examples
+    /// See `examples/abort_on_panic.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.7, see the `MPI_Abort` function
+    pub fn set_abort_on_panic(&self, error_code: Option<Error>) {
+        if let Some(error_code) = error_code {
+            PANIC_ERROR_CODE.store(error_code, atomic::Ordering::Release);
+            PANIC_HOOK.call_once(|| {
+                let previous = panic::take_hook();
+                panic::set_hook(Box::new(move |info| {
+                    if ABORT_ON_PANIC.load(atomic::Ordering::Acquire) {
+                        abort_on_panic_hook(info);
+                    } else {
+                        previous(info);
+                    }
+                }));
+            });
+        }
+        ABORT_ON_PANIC.store(error_code.is_some(), atomic::Ordering::Release);
+    }
+
     fn free_attribute_keys(&mut self) {
         let mut comm_attrs = crate::attribute::COMM_ATTRS.write().unwrap();
         for (_, v) in comm_attrs.drain() {
@@ -161,15 +319,25 @@ impl Universe {
 
 impl Drop for Universe {
     fn drop(&mut self) {
-        // This can only ever be called once since it's only possible to initialize a single
-        // Universe per application run.
+        // This can only ever finalize MPI once since it's only possible to initialize a single
+        // Universe per application run.  Universes obtained from `attach()` leave MPI to the
+        // code that initialized it.
         //
-        // NOTE: The write lock is taken to prevent racing with `#[derive(Equivalence)]`
+        // NOTE: The write lock is taken to prevent racing with `#[derive(Equivalence)]`.  Thread
+        // checks and finalize hooks would take the lock again, so they are done before.
+        check_thread();
+        if self.finalize {
+            run_finalize_hooks_early();
+            self.set_thread_checks(false);
+        }
         let mut _universe_state = UNIVERSE_STATE
             .write()
             .expect("rsmpi internal error: UNIVERSE_STATE lock poisoned");
 
         self.detach_buffer();
+        if !self.finalize {
+            return;
+        }
         self.disconnect_parent();
         self.free_attribute_keys();
         unsafe {
@@ -178,6 +346,21 @@ impl Drop for Universe {
     }
 }
 
+/// The processes that can perform I/O, see `Universe::io_process()`
+///
+/// # Standard section(s)
+///
+/// 8.1.2
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IoProcess {
+    /// Every process can perform I/O
+    Any,
+    /// The process with this rank in the world communicator can perform I/O
+    Rank(Rank),
+    /// No process can perform I/O
+    None,
+}
+
 /// Describes the various levels of multithreading that can be supported by an MPI library.
 ///
 /// # Examples
@@ -240,14 +423,113 @@ impl From<c_int> for Threading {
     }
 }
 
+/// Panic if thread checks are enabled and the calling thread may not use MPI.
+pub(crate) fn check_thread() {
+    if THREAD_CHECKS.load(atomic::Ordering::Acquire) {
+        check_thread_against_universe_state();
+    }
+}
+
+#[cold]
+fn check_thread_against_universe_state() {
+    let universe_state = UNIVERSE_STATE
+        .read()
+        .expect("rsmpi internal error: UNIVERSE_STATE lock poisoned");
+    let Some(universe_state) = universe_state.as_ref() else {
+        return;
+    };
+
+    if matches!(
+        universe_state.threading,
+        Threading::Single | Threading::Funneled
+    ) && universe_state.main_thread != thread::current().id()
+    {
+        panic!(
+            "\n\
+             RSMPI PANIC: MPI used from a thread that is not allowed to\n\
+             \n\
+             MPI was initialized with `Threading::{:?}`, so only the thread that initialized MPI \
+             may use it, but it was used from thread {:?}. Use MPI only from the main thread, or \
+             initialize MPI using `mpi::initialize_with_threading(mpi::Threading::Serialized)` \
+             or `mpi::Threading::Multiple` and check the provided level.\n",
+            universe_state.threading,
+            thread::current().name().unwrap_or("<unnamed>")
+        );
+    }
+}
+
+/// Print the panic message with the rank as prefix and abort, unless the abort is deferred.
+fn abort_on_panic_hook(info: &dyn fmt::Display) {
+    if is_initialized() && !is_finalized() {
+        let (_, rank) =
+            unsafe { with_uninitialized(|rank| ffi::MPI_Comm_rank(ffi::RSMPI_COMM_WORLD, rank)) };
+        eprintln!("[rank {}] {}", rank, info);
+    } else {
+        eprintln!("{}", info);
+    }
+    if DEFERRED_ABORTS.with(Cell::get) == 0 {
+        abort_all_processes();
+    }
+}
+
+#[cold]
+fn abort_all_processes() -> ! {
+    if is_initialized() && !is_finalized() {
+        unsafe {
+            ffi::MPI_Abort(
+                ffi::RSMPI_COMM_WORLD,
+                PANIC_ERROR_CODE.load(atomic::Ordering::Acquire),
+            );
+        }
+    }
+    process::abort();
+}
+
+/// Run `f` and, if it panics while `Universe::set_abort_on_panic()` is active, abort all
+/// processes only after `f` has been unwound.
+///
+/// This completes the outstanding requests of a `request::scope()` inside `f` before MPI is
+/// aborted: while unwinding, every incomplete `Request` is waited for when it is dropped, instead
+/// of panicking.  Calls can be nested, the outermost call aborts.  Without abort on panic, the
+/// panic is resumed.
+///
+/// # Examples
+/// See `examples/abort_on_panic.rs`
+///
+/// # Standard section(s)
+///
+/// 8.7, see the `MPI_Abort` function
+pub fn defer_abort_on_panic<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    DEFERRED_ABORTS.with(|deferred| deferred.set(deferred.get() + 1));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    let deferred = DEFERRED_ABORTS.with(|deferred| {
+        deferred.set(deferred.get() - 1);
+        deferred.get()
+    });
+    match result {
+        Ok(result) => result,
+        Err(_) if deferred == 0 && ABORT_ON_PANIC.load(atomic::Ordering::Acquire) => {
+            abort_all_processes()
+        }
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Whether a panic on this thread aborts all processes only after unwinding into the outermost
+/// `defer_abort_on_panic()`
+pub(crate) fn is_abort_on_panic_deferred() -> bool {
+    ABORT_ON_PANIC.load(atomic::Ordering::Acquire) && DEFERRED_ABORTS.with(Cell::get) != 0
+}
+
 /// Whether the MPI library has been initialized
 pub(crate) fn is_initialized() -> bool {
     unsafe { with_uninitialized(|initialized| ffi::MPI_Initialized(initialized)).1 != 0 }
 }
 
-/// Whether the MPI library has been initialized
-/// NOTE: Used by "derive" feature
-#[allow(unused)]
+/// Whether the MPI library has been finalized
 pub(crate) fn is_finalized() -> bool {
     unsafe { with_uninitialized(|finalized| ffi::MPI_Finalized(finalized)).1 != 0 }
 }
@@ -310,11 +592,158 @@ pub fn initialize_with_threading(threading: Threading) -> Option<(Universe, Thre
 
     // No need to check if UNIVERSE_STATE has already been set - only one thread can enter this
     // code section per MPI run thanks to the `is_initialized()` check before.
+    let provided = provided.into();
     *universe_state = Some(UniverseState {
         main_thread: thread::current().id(),
+        threading: provided,
     });
 
-    Some((Universe { buffer: None }, provided.into()))
+    Some((
+        Universe {
+            buffer: None,
+            finalize: true,
+        },
+        provided,
+    ))
+}
+
+/// Attach to an MPI library that has already been initialized by other code.
+///
+/// This allows a Rust library that is called from an MPI program written in another language to
+/// use MPI through a `Universe`.  Unlike a `Universe` returned by `initialize()`, the returned
+/// `Universe` does not finalize MPI when it is dropped, so `attach()` can be called any number of
+/// times.  Returns `None` if MPI has not been initialized or has already been finalized.
+///
+/// `#[derive(Equivalence)]` assumes that `attach()` is first called from the thread that
+/// initialized MPI.
+///
+/// # Examples
+/// See `examples/attach.rs`
+///
+/// # Standard section(s)
+///
+/// 8.7
+pub fn attach() -> Option<Universe> {
+    let mut universe_state = UNIVERSE_STATE
+        .write()
+        .expect("rsmpi internal error: UNIVERSE_STATE lock poisoned");
+
+    if !is_initialized() || is_finalized() {
+        return None;
+    }
+
+    if universe_state.is_none() {
+        *universe_state = Some(UniverseState {
+            main_thread: thread::current().id(),
+            threading: threading_support(),
+        });
+    }
+
+    Some(Universe {
+        buffer: None,
+        finalize: false,
+    })
+}
+
+/// Register `hook` to be run when MPI is finalized.
+///
+/// Hooks run while MPI can still be used, e.g. to free datatypes or windows: when the `Universe`
+/// returned by `initialize()` is dropped, right before MPI is finalized, or at the start of
+/// `MPI_Finalize()` if MPI is finalized by code in another language after `attach()`.  Hooks run
+/// in the reverse order of their registration.  A panic in a hook aborts the process.
+///
+/// MPI has to be initialized when the hook is registered.
+///
+/// # Examples
+/// See `examples/attach.rs`
+///
+/// # Standard section(s)
+///
+/// 8.7.1
+pub fn at_finalize<F>(hook: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    let mut finalize_hooks = lock_finalize_hooks();
+    if finalize_hooks.key.is_none() {
+        // MPI_Finalize deletes the attributes of MPI_COMM_SELF before anything else is finalized
+        unsafe {
+            let (_, key) = with_uninitialized(|key| {
+                ffi::MPI_Comm_create_keyval(
+                    Some(no_copy_attr),
+                    Some(run_finalize_hooks),
+                    key,
+                    ptr::null_mut(),
+                )
+            });
+            ffi::MPI_Comm_set_attr(ffi::RSMPI_COMM_SELF, key, ptr::null_mut());
+            finalize_hooks.key = Some(key);
+        }
+    }
+    finalize_hooks.hooks.push(Box::new(hook));
+}
+
+fn lock_finalize_hooks() -> MutexGuard<'static, FinalizeHooks> {
+    FINALIZE_HOOKS
+        .lock()
+        .expect("rsmpi internal error: FINALIZE_HOOKS lock poisoned")
+}
+
+/// Run the `at_finalize()` hooks by deleting their attribute from `MPI_COMM_SELF`.
+///
+/// `Universe::drop` runs them before taking the `UNIVERSE_STATE` lock, which hooks may need e.g.
+/// for `attach()` or `#[derive(Equivalence)]`.
+fn run_finalize_hooks_early() {
+    // Hooks may register further hooks
+    loop {
+        let Some(mut key) = lock_finalize_hooks().key.take() else {
+            break;
+        };
+        unsafe {
+            ffi::MPI_Comm_delete_attr(ffi::RSMPI_COMM_SELF, key);
+            ffi::MPI_Comm_free_keyval(&mut key);
+        }
+    }
+}
+
+unsafe extern "C" fn no_copy_attr(
+    _old_comm: ffi::MPI_Comm,
+    _key: c_int,
+    _extra_state: *mut c_void,
+    _val_in: *mut c_void,
+    _val_out: *mut c_void,
+    flag: *mut c_int,
+) -> c_int {
+    *flag = 0;
+    ffi::MPI_SUCCESS
+        .value_as()
+        .expect("MPI_SUCCESS cannot be expressed as a c_int")
+}
+
+unsafe extern "C" fn run_finalize_hooks(
+    _comm: ffi::MPI_Comm,
+    _key: c_int,
+    _val: *mut c_void,
+    _extra_state: *mut c_void,
+) -> c_int {
+    // Release the lock before running the hooks
+    let hooks = {
+        let mut finalize_hooks = lock_finalize_hooks();
+        finalize_hooks.key = None;
+        mem::take(&mut finalize_hooks.hooks)
+    };
+    // Unwinding into MPI is undefined behavior
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        for hook in hooks.into_iter().rev() {
+            hook();
+        }
+    }));
+    if result.is_err() {
+        process::abort();
+    }
+    ffi::MPI_SUCCESS
+        .value_as()
+        .expect("MPI_SUCCESS cannot be expressed as a c_int")
 }
 
 /// Level of multithreading supported by this MPI universe
@@ -0,0 +1,335 @@
+//! Error handling
+//!
+//! By default, MPI aborts the program if an operation on a communicator fails.  Installing
+//! `ErrorHandler::errors_return()` on a communicator with `Communicator::set_error_handler()`
+//! makes MPI return an error code instead.  The `try_*` variants of operations, e.g.
+//! `try_send()`, `try_receive_into()`, `try_barrier()` or `Request::try_wait()`, turn these codes
+//! into an `MpiError`.  The other operations ignore error codes.
+//!
+//! New communicators inherit the error handler of the communicator they are created from.
+//!
+//! Error handlers defined by closures can be installed on communicators, windows and files, e.g.
+//! to log the failure before the program is aborted.  Files opened by MPI use the error handler of
+//! `MPI_FILE_NULL`, which is set with `set_default_file_error_handler()`.
+//!
+//! Libraries can register their own error classes and codes with `add_error_class()` and
+//! `add_error_code()`, and raise them through `Communicator::call_error_handler()`, so they reach
+//! the installed error handlers like errors raised by MPI.
+
+use std::ffi::CString;
+use std::fmt;
+#[cfg(feature = "user-operations")]
+use std::mem::ManuallyDrop;
+use std::os::raw::c_int;
+#[cfg(feature = "user-operations")]
+use std::os::raw::c_void;
+
+#[cfg(feature = "user-operations")]
+use libffi::middle::{Cif, Closure, Type};
+
+use crate::ffi;
+use crate::ffi::{MPI_Comm, MPI_Errhandler};
+#[cfg(feature = "user-operations")]
+use crate::ffi::{MPI_File, MPI_Win};
+
+use crate::environment::threading_support;
+use crate::raw::traits::*;
+#[cfg(feature = "user-operations")]
+use crate::topology::{sealed::CommunicatorHandle, SimpleCommunicator};
+use crate::with_uninitialized;
+use crate::{check, Error, MpiError, Threading};
+
+/// A handler for errors on communicators, windows or files
+///
+/// A user-defined handler may only be installed on the kind of object it was created for.
+///
+/// # Examples
+///
+/// See `examples/error_handling.rs`
+///
+/// # Standard section(s)
+///
+/// 8.3
+pub struct ErrorHandler {
+    handle: MPI_Errhandler,
+    owned: bool,
+}
+
+impl fmt::Debug for ErrorHandler {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_tuple("ErrorHandler")
+            .field(&self.handle)
+            .finish()
+    }
+}
+
+impl Drop for ErrorHandler {
+    fn drop(&mut self) {
+        if self.owned {
+            unsafe {
+                ffi::MPI_Errhandler_free(&mut self.handle);
+            }
+        }
+    }
+}
+
+unsafe impl AsRaw for ErrorHandler {
+    type Raw = MPI_Errhandler;
+    fn as_raw(&self) -> Self::Raw {
+        self.handle
+    }
+}
+
+impl ErrorHandler {
+    /// The predefined handler that aborts all processes, `MPI_ERRORS_ARE_FATAL`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3
+    pub fn errors_are_fatal() -> ErrorHandler {
+        ErrorHandler {
+            handle: unsafe { ffi::RSMPI_ERRORS_ARE_FATAL },
+            owned: false,
+        }
+    }
+
+    /// The predefined handler that returns error codes to the caller, `MPI_ERRORS_RETURN`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3
+    pub fn errors_return() -> ErrorHandler {
+        ErrorHandler {
+            handle: unsafe { ffi::RSMPI_ERRORS_RETURN },
+            owned: false,
+        }
+    }
+
+    /// Wraps a handle returned by MPI, which is freed on drop.
+    pub(crate) fn from_owned_raw(handle: MPI_Errhandler) -> ErrorHandler {
+        ErrorHandler {
+            handle,
+            owned: true,
+        }
+    }
+
+    /// Define a handler for errors on communicators using a closure.
+    ///
+    /// The closure receives the communicator the error was raised on and the error code.  Unless
+    /// it aborts the program, e.g. with `Communicator::abort()`, the failing operation returns the
+    /// error code to its caller.
+    ///
+    /// The closure is never deallocated, since MPI may invoke it as long as the handler is installed
+    /// on any communicator, even after `self` has been dropped.
+    ///
+    /// **Note:** If the closure panics, the entire program will abort.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handling.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.1
+    #[cfg(feature = "user-operations")]
+    pub fn communicator<F>(function: F) -> ErrorHandler
+    where
+        F: Fn(&SimpleCommunicator, Error) + Sync + 'static,
+    {
+        let closure = leak_closure(move |comm: MPI_Comm, code| {
+            let comm = ManuallyDrop::new(SimpleCommunicator(unsafe {
+                CommunicatorHandle::try_from_raw(comm).expect("Error raised on MPI_COMM_NULL")
+            }));
+            function(&comm, code)
+        });
+        let handle = unsafe {
+            with_uninitialized(|handle| {
+                ffi::MPI_Comm_create_errhandler(Some(*closure.instantiate_code_ptr()), handle)
+            })
+            .1
+        };
+        ErrorHandler::from_owned_raw(handle)
+    }
+
+    /// Define a handler for errors on windows using a closure.
+    ///
+    /// The closure receives the raw handle of the window the error was raised on and the error
+    /// code.  Like the closures of `communicator()`, it is never deallocated.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.2
+    #[cfg(feature = "user-operations")]
+    pub fn window<F>(function: F) -> ErrorHandler
+    where
+        F: Fn(MPI_Win, Error) + Sync + 'static,
+    {
+        let closure = leak_closure(function);
+        let handle = unsafe {
+            with_uninitialized(|handle| {
+                ffi::MPI_Win_create_errhandler(Some(*closure.instantiate_code_ptr()), handle)
+            })
+            .1
+        };
+        ErrorHandler::from_owned_raw(handle)
+    }
+
+    /// Define a handler for errors on files using a closure.
+    ///
+    /// The closure receives the raw handle of the file the error was raised on and the error code.
+    /// Like the closures of `communicator()`, it is never deallocated.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.3
+    #[cfg(feature = "user-operations")]
+    pub fn file<F>(function: F) -> ErrorHandler
+    where
+        F: Fn(MPI_File, Error) + Sync + 'static,
+    {
+        let closure = leak_closure(function);
+        let handle = unsafe {
+            with_uninitialized(|handle| {
+                ffi::MPI_File_create_errhandler(Some(*closure.instantiate_code_ptr()), handle)
+            })
+            .1
+        };
+        ErrorHandler::from_owned_raw(handle)
+    }
+}
+
+/// Turn `function` into a callback with the signature of the error handler functions, which take
+/// pointers to an object handle `H` and to an error code.
+#[cfg(feature = "user-operations")]
+fn leak_closure<H, F>(function: F) -> &'static Closure<'static>
+where
+    H: Copy + 'static,
+    F: Fn(H, Error) + Sync + 'static,
+{
+    let function: &'static F = Box::leak(Box::new(function));
+
+    let args = [
+        Type::pointer(), // H *
+        Type::pointer(), // int *
+    ];
+    #[allow(unused_mut)]
+    let mut cif = Cif::new(args.iter().cloned(), Type::void());
+    // MS-MPI uses "stdcall" calling convention on 32-bit x86
+    #[cfg(all(msmpi, target_arch = "x86"))]
+    cif.set_abi(libffi::raw::ffi_abi_FFI_STDCALL);
+
+    unsafe extern "C" fn trampoline<H: Copy, F: Fn(H, Error)>(
+        cif: &libffi::low::ffi_cif,
+        _result: &mut c_void,
+        args: *const *const c_void,
+        user_function: &F,
+    ) {
+        debug_assert_eq!(2, cif.nargs);
+
+        let (handle, code) = (
+            *(*args.offset(0) as *const *mut H),
+            *(*args.offset(1) as *const *mut c_int),
+        );
+
+        user_function(*handle, *code)
+    }
+
+    Box::leak(Box::new(Closure::new(cif, trampoline::<H, F>, function)))
+}
+
+/// Set the handler for errors raised when opening files, i.e. the error handler of
+/// `MPI_FILE_NULL`.
+///
+/// Files inherit this handler when they are opened.
+///
+/// # Standard section(s)
+///
+/// 8.3.3, 14.7
+pub fn set_default_file_error_handler(handler: &ErrorHandler) {
+    unsafe {
+        ffi::MPI_File_set_errhandler(ffi::RSMPI_FILE_NULL, handler.as_raw());
+    }
+}
+
+/// The handler for errors raised when opening files, i.e. the error handler of `MPI_FILE_NULL`
+///
+/// # Standard section(s)
+///
+/// 8.3.3, 14.7
+pub fn default_file_error_handler() -> ErrorHandler {
+    let handle = unsafe {
+        with_uninitialized(|handle| ffi::MPI_File_get_errhandler(ffi::RSMPI_FILE_NULL, handle)).1
+    };
+    ErrorHandler::from_owned_raw(handle)
+}
+
+/// Register a new error class, which is distinct from all predefined classes.
+///
+/// Processes that communicate errors of this class should register their classes in the same
+/// order, since the returned values may be assigned independently on each process.
+///
+/// # Examples
+///
+/// See `examples/error_handling.rs`
+///
+/// # Standard section(s)
+///
+/// 8.5, see the `MPI_Add_error_class` function
+pub fn add_error_class() -> Error {
+    unsafe { with_uninitialized(|class| ffi::MPI_Add_error_class(class)).1 }
+}
+
+/// Register a new error code in the error class `class`.
+///
+/// `class` has to be a predefined class or one returned by `add_error_class()`.
+///
+/// # Examples
+///
+/// See `examples/error_handling.rs`
+///
+/// # Standard section(s)
+///
+/// 8.5, see the `MPI_Add_error_code` function
+pub fn add_error_code(class: Error) -> Error {
+    unsafe { with_uninitialized(|code| ffi::MPI_Add_error_code(class, code)).1 }
+}
+
+/// Set the description of a user-defined error class or code, which is returned by
+/// `MPI_Error_string()` and used by `MpiError::from_code()`.
+///
+/// The description must be shorter than `MPI_MAX_ERROR_STRING` bytes.  Fails if `string` contains
+/// an interior 0 byte or `code` is not a user-defined class or code.
+///
+/// # Examples
+///
+/// See `examples/error_handling.rs`
+///
+/// # Standard section(s)
+///
+/// 8.5, see the `MPI_Add_error_string` function
+pub fn add_error_string(code: Error, string: &str) -> Result<(), MpiError> {
+    let string = CString::new(string)?;
+    check("MPI_Add_error_string", unsafe {
+        ffi::MPI_Add_error_string(code, string.as_ptr())
+    })
+}
+
+/// Call `f` with errors on `comm` returned instead of passed to the error handler of `comm`.
+///
+/// With `Threading::Multiple`, other threads may use `comm` while its handler would be swapped, so
+/// the handler of `comm` is left in place and decides whether errors are returned.
+pub(crate) unsafe fn with_errors_returned<F>(comm: MPI_Comm, f: F) -> c_int
+where
+    F: FnOnce() -> c_int,
+{
+    if threading_support() == Threading::Multiple {
+        return f();
+    }
+    let mut handler = with_uninitialized(|handler| ffi::MPI_Comm_get_errhandler(comm, handler)).1;
+    ffi::MPI_Comm_set_errhandler(comm, ffi::RSMPI_ERRORS_RETURN);
+    let code = f();
+    ffi::MPI_Comm_set_errhandler(comm, handler);
+    ffi::MPI_Errhandler_free(&mut handler);
+    code
+}
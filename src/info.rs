@@ -0,0 +1,239 @@
+//! Info objects
+//!
+//! An `Info` object is an unordered set of key/value pairs of strings.  Info objects pass hints,
+//! e.g. about the expected usage of a window or the hosts of spawned processes, to MPI.  MPI
+//! ignores keys it does not know.
+//!
+//! # Examples
+//!
+//! See `examples/info.rs`
+//!
+//! # Standard section(s)
+//!
+//! 9
+
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::{c_char, c_int};
+
+use conv::ConvUtil;
+
+use crate::ffi;
+use crate::ffi::MPI_Info;
+use crate::raw::traits::*;
+use crate::{check, with_uninitialized, MpiError};
+
+/// An owned set of key/value pairs
+///
+/// # Examples
+///
+/// See `examples/info.rs`
+///
+/// # Standard section(s)
+///
+/// 9
+pub struct Info(MPI_Info);
+
+impl Info {
+    /// Create an empty info object.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_create` function
+    pub fn new() -> Info {
+        Info(unsafe { with_uninitialized(|info| ffi::MPI_Info_create(info)).1 })
+    }
+
+    /// Set the value of `key` to `value`, replacing any previous value.
+    ///
+    /// Keys must be shorter than `MPI_MAX_INFO_KEY` and values shorter than `MPI_MAX_INFO_VAL`
+    /// bytes.  Fails if `key` or `value` contain an interior 0 byte.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_set` function
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), MpiError> {
+        let key = CString::new(key)?;
+        let value = CString::new(value)?;
+        check("MPI_Info_set", unsafe {
+            ffi::MPI_Info_set(self.0, key.as_ptr(), value.as_ptr())
+        })
+    }
+
+    /// The value of `key` or `None` if `key` is not set.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_get_valuelen` and `MPI_Info_get` functions
+    pub fn get(&self, key: &str) -> Option<String> {
+        let key = CString::new(key).ok()?;
+        let mut len: c_int = 0;
+        let mut flag: c_int = 0;
+        unsafe {
+            ffi::MPI_Info_get_valuelen(self.0, key.as_ptr(), &mut len, &mut flag);
+        }
+        if flag == 0 {
+            return None;
+        }
+
+        let mut value = vec![0u8; len.value_as::<usize>().expect("Negative value length") + 1];
+        unsafe {
+            ffi::MPI_Info_get(
+                self.0,
+                key.as_ptr(),
+                len,
+                value.as_mut_ptr() as *mut c_char,
+                &mut flag,
+            );
+        }
+        Some(
+            CStr::from_bytes_until_nul(&value)
+                .expect("MPI_Info_get returned an unterminated string")
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+
+    /// Remove `key` and return whether it was set.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_delete` function
+    pub fn delete(&mut self, key: &str) -> bool {
+        if self.get(key).is_none() {
+            return false;
+        }
+        let key = CString::new(key).expect("Keys of set values do not contain 0 bytes");
+        unsafe {
+            ffi::MPI_Info_delete(self.0, key.as_ptr());
+        }
+        true
+    }
+
+    /// The number of keys that are set
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_get_nkeys` function
+    pub fn len(&self) -> usize {
+        let nkeys = unsafe { with_uninitialized(|nkeys| ffi::MPI_Info_get_nkeys(self.0, nkeys)).1 };
+        nkeys.value_as().expect("Negative number of keys")
+    }
+
+    /// Whether no keys are set
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The `n`th key that is set
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_get_nthkey` function
+    fn nth_key(&self, n: c_int) -> String {
+        let mut key = vec![0u8; ffi::MPI_MAX_INFO_KEY.value_as::<usize>().unwrap_or(256) + 1];
+        unsafe {
+            ffi::MPI_Info_get_nthkey(self.0, n, key.as_mut_ptr() as *mut c_char);
+        }
+        CStr::from_bytes_until_nul(&key)
+            .expect("MPI_Info_get_nthkey returned an unterminated string")
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Iterate over the key/value pairs in the order of the keys in the info object.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            info: self,
+            next: 0,
+            len: self.len().value_as().expect("Number of keys exceeds c_int"),
+        }
+    }
+
+    /// Create a new info object with the same key/value pairs.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 9, see the `MPI_Info_dup` function
+    pub fn dup(&self) -> Info {
+        Info(unsafe { with_uninitialized(|info| ffi::MPI_Info_dup(self.0, info)).1 })
+    }
+}
+
+impl Default for Info {
+    fn default() -> Self {
+        Info::new()
+    }
+}
+
+impl Clone for Info {
+    fn clone(&self) -> Self {
+        self.dup()
+    }
+}
+
+impl Drop for Info {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Info_free(&mut self.0);
+        }
+    }
+}
+
+impl fmt::Debug for Info {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_map().entries(self.iter()).finish()
+    }
+}
+
+unsafe impl AsRaw for Info {
+    type Raw = MPI_Info;
+    fn as_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+impl FromRaw for Info {
+    /// Wraps an info object, which is freed on drop.
+    ///
+    /// # Safety
+    /// - `handle` must be a live `MPI_Info` object other than `MPI_INFO_NULL` or `MPI_INFO_ENV`.
+    /// - `handle` must not be used after calling this function.
+    unsafe fn from_raw(handle: MPI_Info) -> Self {
+        Info(handle)
+    }
+}
+
+impl<'a> IntoIterator for &'a Info {
+    type Item = (String, String);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the key/value pairs of an `Info` object
+#[derive(Debug)]
+pub struct Iter<'a> {
+    info: &'a Info,
+    next: c_int,
+    len: c_int,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (String, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.len {
+            return None;
+        }
+        let key = self.info.nth_key(self.next);
+        self.next += 1;
+        let value = self
+            .info
+            .get(&key)
+            .expect("Keys returned by MPI_Info_get_nthkey are set");
+        Some((key, value))
+    }
+}
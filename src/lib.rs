@@ -94,6 +94,9 @@
 //!   - send-receive
 //!   - probe
 //!   - matched probe/receive
+//!   - persistent requests
+//!   - partitioned communication, if the MPI library implements MPI 4.0
+//!   - typed channels
 //! - **Collective communication**:
 //!   - barrier
 //!   - broadcast
@@ -105,6 +108,10 @@
 //!   - blocking and non-blocking variants
 //! - **Datatypes**: Bridging between Rust types and MPI basic types as well as custom MPI datatypes
 //! which can act as views into buffers.
+//! - **Sessions**: the MPI 4.0 Sessions model, if the MPI library implements MPI 4.0
+//! - **Memory allocation**: `MPI_Alloc_mem()` backed boxes and vectors
+//! - **Info objects**: hints for communicators, windows and process creation
+//! - **External interfaces**: generalized requests
 //!
 //! Not supported (yet):
 //!
@@ -124,7 +131,9 @@
 //! [MPIspec]: http://www.mpi-forum.org/docs/docs.html
 
 use std::mem::MaybeUninit;
-use std::os::raw::c_int;
+use std::os::raw::{c_char, c_int};
+
+use conv::ConvUtil;
 
 /// The raw C language MPI API
 ///
@@ -138,12 +147,22 @@ pub mod ffi {
 }
 
 pub mod attribute;
+pub mod channel;
 pub mod collective;
 pub mod datatype;
 pub mod environment;
+pub mod error_handling;
+pub mod info;
+pub mod memory;
+#[cfg(mpi4)]
+pub mod partitioned;
 pub mod point_to_point;
 pub mod raw;
 pub mod request;
+#[cfg(feature = "serde")]
+pub mod serialization;
+#[cfg(mpi4)]
+pub mod session;
 pub mod topology;
 pub mod window;
 /// Re-exports all traits.
@@ -171,7 +190,7 @@ pub mod internal {
 
 #[doc(inline)]
 pub use crate::environment::{
-    initialize, initialize_with_threading, time, time_resolution, Threading,
+    at_finalize, attach, initialize, initialize_with_threading, time, time_resolution, Threading,
 };
 
 use crate::ffi::MPI_Aint;
@@ -216,23 +235,91 @@ where
 
 /// Errors
 ///
-/// RSMPI is currently configured with MPI_ERRORS_ARE_FATAL, but:
-///
-/// 1. we intend to remove this restriction at some point
+/// By default, MPI aborts the program when an MPI function fails.  After installing
+/// `ErrorHandler::errors_return()` on a communicator, failures of the `try_*` operations on it are
+/// returned as `MpiError::Call`, see the [`error_handling`](error_handling/index.html) module.
 ///
-/// 2. we need to be able to return parse errors and it seems better to make a
-/// stable error type than to propagate raw types like ``std::ffi::NulError` in
-/// our public interface.
+/// Errors detected by RSMPI itself, such as parse errors, are reported through the same type,
+/// since it seems better to make a stable error type than to propagate raw types like
+/// `std::ffi::NulError` in our public interface.
 ///
 /// # Standard section(s)
 ///
-/// 9.3
+/// 9.3, 9.4
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum MpiError {
+    /// An MPI function returned an error
+    #[error("{call} failed with error class {class}: {message}")]
+    Call {
+        /// The name of the MPI function that failed
+        call: &'static str,
+        /// The error code returned by the function
+        code: Error,
+        /// The error class of `code`, see `MPI_Error_class()`
+        class: Error,
+        /// The description of `code`, see `MPI_Error_string()`
+        message: String,
+    },
     /// Failed to spawn some processes
     #[error("Failed to spawn {0} of {1} processes")]
     Spawn(Rank, Rank),
     /// CString::new fails if a Rust string contains interior 0 bytes
     #[error("An interior 0 byte was found in string")]
     StringNul(#[from] std::ffi::NulError),
+    /// A tag is negative or larger than `MPI_TAG_UB`
+    #[error("Tag {0} is outside of the valid range 0..={1}")]
+    TagOutOfRange(Tag, Tag),
+    /// A tag has already been reserved
+    #[error("Tag {0} is already in use")]
+    TagInUse(Tag),
+    /// A predefined attribute is not cached on the communicator
+    #[error("The attribute {0} is not set")]
+    AttributeNotSet(&'static str),
+    /// Memory allocated by MPI is not aligned to the given number of bytes
+    #[error("MPI_Alloc_mem returned memory that is not aligned to {0} bytes")]
+    Misaligned(usize),
+    /// A buffered mode send of the given size in bytes did not fit into the attached buffer
+    #[error("A buffered mode send of {0} bytes exceeds the space in the attached buffer")]
+    BufferedSendOverflow(usize),
+}
+
+impl MpiError {
+    /// Describe the error `code` returned by the MPI function `call`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.4
+    pub fn from_code(call: &'static str, code: Error) -> MpiError {
+        let class = unsafe { with_uninitialized(|class| ffi::MPI_Error_class(code, class)).1 };
+        let mut message = vec![0u8; ffi::MPI_MAX_ERROR_STRING.value_as().unwrap_or(256)];
+        let mut len: c_int = 0;
+        unsafe {
+            ffi::MPI_Error_string(code, message.as_mut_ptr() as *mut c_char, &mut len);
+        }
+        message.truncate(len.value_as().unwrap_or(0));
+        MpiError::Call {
+            call,
+            code,
+            class,
+            message: String::from_utf8_lossy(&message).into_owned(),
+        }
+    }
+
+    /// The error class of an error returned by an MPI function
+    pub fn class(&self) -> Option<Error> {
+        match *self {
+            MpiError::Call { class, .. } => Some(class),
+            _ => None,
+        }
+    }
+}
+
+/// Turn the `code` returned by the MPI function `call` into a `Result`.
+fn check(call: &'static str, code: Error) -> Result<(), MpiError> {
+    if code.value_as::<u32>().ok() == Some(ffi::MPI_SUCCESS) {
+        Ok(())
+    } else {
+        Err(MpiError::from_code(call, code))
+    }
 }
@@ -0,0 +1,427 @@
+//! Memory allocation
+//!
+//! `MPI_Alloc_mem()` can provide memory that speeds up communication, e.g. because it is
+//! registered with the network for remote memory access.  `MpiAllocator` exposes it through an
+//! interface like that of the `Allocator` trait of the standard library.  `MpiBox` and `MpiVec`
+//! own values and vectors in such memory and can be used as communication buffers.
+//!
+//! # Examples
+//!
+//! See `examples/alloc_mem.rs`
+//!
+//! # Standard section(s)
+//!
+//! 8.2
+
+use std::alloc::Layout;
+use std::marker::PhantomData;
+use std::mem::{size_of, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::os::raw::c_void;
+use std::ptr::{self, NonNull};
+use std::{fmt, slice};
+
+use conv::ConvUtil;
+
+use crate::datatype::traits::*;
+use crate::info::Info;
+use crate::raw::traits::*;
+use crate::{check, ffi, Count, MpiError};
+
+/// Allocates memory with `MPI_Alloc_mem()`
+///
+/// # Examples
+///
+/// See `examples/alloc_mem.rs`
+///
+/// # Standard section(s)
+///
+/// 8.2
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MpiAllocator;
+
+impl MpiAllocator {
+    /// Allocate memory for `layout`.
+    ///
+    /// Zero-sized layouts do not allocate.  Fails if MPI does not align the memory as required by
+    /// `layout`.
+    ///
+    /// If MPI cannot provide the memory, the error handler of `MPI_COMM_WORLD` (of
+    /// `MPI_COMM_SELF` in MPI 4.0 and later) is invoked.  The default handler aborts the program,
+    /// like allocation failures in the standard library do.  An error is only returned if that
+    /// handler is set to return errors, e.g. with `ErrorHandler::errors_return()`.  The handler is
+    /// not changed here, since that would race with other threads using MPI.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.2, see the `MPI_Alloc_mem` function
+    pub fn allocate(&self, layout: Layout) -> Result<NonNull<u8>, MpiError> {
+        if layout.size() == 0 {
+            return Ok(dangling(layout));
+        }
+
+        // Honored by MPI 4.1 libraries, older ones ignore it
+        let mut info = Info::new();
+        info.set("mpi_minimum_memory_alignment", &layout.align().to_string())?;
+
+        let size: ffi::MPI_Aint = layout
+            .size()
+            .value_as()
+            .expect("Layout size exceeds the range of MPI_Aint");
+        let mut base: *mut c_void = ptr::null_mut();
+        let code =
+            unsafe { ffi::MPI_Alloc_mem(size, info.as_raw(), ptr::addr_of_mut!(base).cast()) };
+        check("MPI_Alloc_mem", code)?;
+
+        let base = NonNull::new(base as *mut u8).expect("MPI_Alloc_mem returned a null pointer");
+        if (base.as_ptr() as usize) % layout.align() != 0 {
+            unsafe {
+                ffi::MPI_Free_mem(base.as_ptr() as _);
+            }
+            return Err(MpiError::Misaligned(layout.align()));
+        }
+        Ok(base)
+    }
+
+    /// Free memory returned by `allocate()`.
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by `allocate()` for the same `layout` and must not be used
+    /// afterwards.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.2, see the `MPI_Free_mem` function
+    pub unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        if layout.size() != 0 {
+            ffi::MPI_Free_mem(ptr.as_ptr() as _);
+        }
+    }
+}
+
+/// A pointer that is aligned for `layout` but does not point to an allocation
+fn dangling(layout: Layout) -> NonNull<u8> {
+    NonNull::new(layout.align() as *mut u8).expect("Alignments are not zero")
+}
+
+/// An owned value in memory allocated with `MPI_Alloc_mem()`, like `Box`
+///
+/// # Examples
+///
+/// See `examples/alloc_mem.rs`
+pub struct MpiBox<T: ?Sized> {
+    ptr: NonNull<T>,
+    phantom: PhantomData<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for MpiBox<T> {}
+unsafe impl<T: ?Sized + Sync> Sync for MpiBox<T> {}
+
+impl<T> MpiBox<T> {
+    /// Move `value` into memory allocated with `MPI_Alloc_mem()`.
+    pub fn new(value: T) -> Result<MpiBox<T>, MpiError> {
+        let ptr = MpiAllocator.allocate(Layout::new::<T>())?.cast::<T>();
+        unsafe {
+            ptr.as_ptr().write(value);
+        }
+        Ok(MpiBox {
+            ptr,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: Clone> MpiBox<[T]> {
+    /// Copy `values` into memory allocated with `MPI_Alloc_mem()`.
+    pub fn from_slice(values: &[T]) -> Result<MpiBox<[T]>, MpiError> {
+        let mut vec = MpiVec::with_capacity(values.len())?;
+        vec.extend_from_slice(values);
+        let vec = ManuallyDrop::new(vec);
+        Ok(MpiBox {
+            ptr: NonNull::slice_from_raw_parts(vec.ptr, vec.len),
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<T: ?Sized> Deref for MpiBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MpiBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<T: ?Sized> Drop for MpiBox<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let layout = Layout::for_value(self.ptr.as_ref());
+            ptr::drop_in_place(self.ptr.as_ptr());
+            MpiAllocator.deallocate(self.ptr.cast(), layout);
+        }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for MpiBox<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, formatter)
+    }
+}
+
+/// A growable vector in memory allocated with `MPI_Alloc_mem()`, like `Vec`
+///
+/// Growing the vector moves its elements to a new allocation, since MPI cannot reallocate memory.
+///
+/// # Examples
+///
+/// See `examples/alloc_mem.rs`
+pub struct MpiVec<T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    len: usize,
+}
+
+unsafe impl<T: Send> Send for MpiVec<T> {}
+unsafe impl<T: Sync> Sync for MpiVec<T> {}
+
+impl<T> MpiVec<T> {
+    /// Create an empty vector, which does not allocate.
+    pub fn new() -> MpiVec<T> {
+        MpiVec {
+            ptr: NonNull::dangling(),
+            cap: if size_of::<T>() == 0 { usize::MAX } else { 0 },
+            len: 0,
+        }
+    }
+
+    /// Create an empty vector with space for at least `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Result<MpiVec<T>, MpiError> {
+        let mut vec = MpiVec::new();
+        vec.try_reserve(capacity)?;
+        Ok(vec)
+    }
+
+    /// The number of elements the vector can hold without allocating
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+
+    /// Make room for at least `additional` more elements.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), MpiError> {
+        let required = self.len.checked_add(additional).expect("Capacity overflow");
+        if required <= self.cap {
+            return Ok(());
+        }
+
+        let cap = required.max(self.cap * 2).max(4);
+        let layout = Layout::array::<T>(cap).expect("Capacity overflow");
+        let ptr = MpiAllocator.allocate(layout)?.cast::<T>();
+        unsafe {
+            ptr::copy_nonoverlapping(self.ptr.as_ptr(), ptr.as_ptr(), self.len);
+            self.free();
+        }
+        self.ptr = ptr;
+        self.cap = cap;
+        Ok(())
+    }
+
+    /// Make room for at least `additional` more elements.
+    ///
+    /// Panics if MPI cannot allocate the memory.
+    pub fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional)
+            .expect("MPI_Alloc_mem failed to grow an MpiVec");
+    }
+
+    /// Append `value`.
+    ///
+    /// Panics if MPI cannot allocate the memory.
+    pub fn push(&mut self, value: T) {
+        if self.len == self.cap {
+            self.reserve(1);
+        }
+        unsafe {
+            self.ptr.as_ptr().add(self.len).write(value);
+        }
+        self.len += 1;
+    }
+
+    /// Remove and return the last element.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(unsafe { self.ptr.as_ptr().add(self.len).read() })
+    }
+
+    /// Shorten the vector to `len` elements.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            let tail = ptr::slice_from_raw_parts_mut(
+                unsafe { self.ptr.as_ptr().add(len) },
+                self.len - len,
+            );
+            self.len = len;
+            unsafe {
+                ptr::drop_in_place(tail);
+            }
+        }
+    }
+
+    /// Remove all elements, keeping the allocation.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Free the allocation without dropping elements.
+    unsafe fn free(&mut self) {
+        if self.cap != 0 && size_of::<T>() != 0 {
+            let layout = Layout::array::<T>(self.cap).expect("Capacity overflow");
+            MpiAllocator.deallocate(self.ptr.cast(), layout);
+        }
+    }
+}
+
+impl<T: Clone> MpiVec<T> {
+    /// Append clones of `values`.
+    pub fn extend_from_slice(&mut self, values: &[T]) {
+        self.reserve(values.len());
+        for value in values {
+            self.push(value.clone());
+        }
+    }
+}
+
+impl<T> Default for MpiVec<T> {
+    fn default() -> Self {
+        MpiVec::new()
+    }
+}
+
+impl<T> Deref for MpiVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for MpiVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T> Extend<T> for MpiVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> Drop for MpiVec<T> {
+    fn drop(&mut self) {
+        self.clear();
+        unsafe {
+            self.free();
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MpiVec<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&**self, formatter)
+    }
+}
+
+unsafe impl<T> AsDatatype for MpiVec<T>
+where
+    T: Equivalence,
+{
+    type Out = <T as Equivalence>::Out;
+    fn as_datatype(&self) -> Self::Out {
+        <T as Equivalence>::equivalent_datatype()
+    }
+}
+
+unsafe impl<T> Collection for MpiVec<T>
+where
+    T: Equivalence,
+{
+    fn count(&self) -> Count {
+        self.len()
+            .value_as()
+            .expect("Length of slice cannot be expressed as an MPI Count.")
+    }
+}
+
+unsafe impl<T> Pointer for MpiVec<T>
+where
+    T: Equivalence,
+{
+    fn pointer(&self) -> *const c_void {
+        self.as_ptr() as _
+    }
+}
+
+unsafe impl<T> PointerMut for MpiVec<T>
+where
+    T: Equivalence,
+{
+    fn pointer_mut(&mut self) -> *mut c_void {
+        self.as_mut_ptr() as _
+    }
+}
+
+unsafe impl<T> Buffer for MpiVec<T> where T: Equivalence {}
+unsafe impl<T> BufferMut for MpiVec<T> where T: Equivalence {}
+
+unsafe impl<T> AsDatatype for MpiBox<[T]>
+where
+    T: Equivalence,
+{
+    type Out = <T as Equivalence>::Out;
+    fn as_datatype(&self) -> Self::Out {
+        <T as Equivalence>::equivalent_datatype()
+    }
+}
+
+unsafe impl<T> Collection for MpiBox<[T]>
+where
+    T: Equivalence,
+{
+    fn count(&self) -> Count {
+        self.len()
+            .value_as()
+            .expect("Length of slice cannot be expressed as an MPI Count.")
+    }
+}
+
+unsafe impl<T> Pointer for MpiBox<[T]>
+where
+    T: Equivalence,
+{
+    fn pointer(&self) -> *const c_void {
+        self.as_ptr() as _
+    }
+}
+
+unsafe impl<T> PointerMut for MpiBox<[T]>
+where
+    T: Equivalence,
+{
+    fn pointer_mut(&mut self) -> *mut c_void {
+        self.as_mut_ptr() as _
+    }
+}
+
+unsafe impl<T> Buffer for MpiBox<[T]> where T: Equivalence {}
+unsafe impl<T> BufferMut for MpiBox<[T]> where T: Equivalence {}
@@ -0,0 +1,399 @@
+//! Partitioned point to point communication
+//!
+//! A partitioned send transfers a single message whose buffer is split into partitions of equal
+//! size.  After starting the operation, each partition of the send buffer can be filled and marked
+//! as ready independently, e.g. by different threads.  On the receiving side, each partition can
+//! be inspected as soon as it has arrived.
+//!
+//! Partitions are handed out as disjoint `SendPartition` objects, which give mutable access to their
+//! part of the send buffer until they are marked as ready.  Marking a partition as ready consumes
+//! it, so a partition cannot be written to after it has been handed to MPI.
+//!
+//! Marking partitions as ready from several threads requires MPI to be initialized with
+//! `Threading::Multiple`.
+//!
+//! This module is only available if the MPI library implements MPI 4.0.
+//!
+//! # Unfinished features
+//!
+//! - **4.2**: Info arguments of `MPI_Psend_init()`, `MPI_Precv_init()`
+
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::os::raw::c_int;
+use std::{fmt, mem, slice};
+
+use conv::ConvUtil;
+
+use crate::ffi;
+use crate::ffi::MPI_Request;
+
+use crate::raw::traits::*;
+use crate::request::{PersistentRequest, PersistentScope, Request, Scope, StaticScope};
+use crate::{Count, IntArray};
+
+/// Number of elements in each of `partitions` partitions of a buffer of length `len`.
+pub(crate) fn partition_len(len: usize, partitions: Count) -> usize {
+    let partitions: usize = partitions
+        .value_as()
+        .ok()
+        .filter(|&partitions| partitions > 0)
+        .expect("the number of partitions must be positive");
+    assert_eq!(
+        len % partitions,
+        0,
+        "the buffer length must be a multiple of the number of partitions"
+    );
+    len / partitions
+}
+
+/// A persistent partitioned send request registered with a `Scope` of lifetime `'a`
+///
+/// The send buffer is borrowed for the lifetime of the request.  Each call to
+/// [`start()`](#method.start) hands out the partitions of the buffer, which have to be marked as
+/// ready, along with a `Request` for the completion of the whole operation.
+///
+/// # Examples
+///
+/// See `examples/partitioned.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2.2
+pub struct PartitionedSend<'a, T, S: Scope<'a> = StaticScope> {
+    request: PersistentRequest<'a, (), S>,
+    buf: *mut T,
+    partition_len: usize,
+    partitions: Count,
+    phantom: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T, S: Scope<'a>> fmt::Debug for PartitionedSend<'a, T, S> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("PartitionedSend")
+            .field("request", &self.request.as_raw())
+            .field("partitions", &self.partitions)
+            .finish()
+    }
+}
+
+unsafe impl<'a, T, S: Scope<'a>> AsRaw for PartitionedSend<'a, T, S> {
+    type Raw = MPI_Request;
+    fn as_raw(&self) -> Self::Raw {
+        self.request.as_raw()
+    }
+}
+
+impl<'a, T, S: Scope<'a>> PartitionedSend<'a, T, S> {
+    /// Construct a partitioned send request object from the raw MPI type.
+    ///
+    /// # Safety
+    /// - `request` must be a live, inactive, partitioned send request on `buf` with `partitions`
+    ///   partitions.
+    /// - `request` must not be used after calling `from_raw`.
+    pub unsafe fn from_raw(
+        request: MPI_Request,
+        buf: &'a mut [T],
+        partitions: Count,
+        scope: S,
+    ) -> Self {
+        PartitionedSend {
+            partition_len: partition_len(buf.len(), partitions),
+            request: PersistentRequest::from_raw(request, &(), scope),
+            buf: buf.as_mut_ptr(),
+            partitions,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The number of partitions
+    pub fn num_partitions(&self) -> Count {
+        self.partitions
+    }
+
+    /// Start the operation.
+    ///
+    /// Returns the `Request` of the operation and its partitions in ascending order.  The operation
+    /// completes once all partitions have been marked as ready and sent.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/partitioned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9, 4.2.2
+    pub fn start(
+        &mut self,
+    ) -> (
+        Request<'_, (), PersistentScope<'_>>,
+        Vec<SendPartition<'_, T>>,
+    ) {
+        let request = self.request.start();
+        let raw = request.as_raw();
+        let partitions = (0..self.partitions)
+            .zip(0usize..)
+            .map(|(index, i)| SendPartition {
+                data: unsafe {
+                    slice::from_raw_parts_mut(
+                        self.buf.add(i * self.partition_len),
+                        self.partition_len,
+                    )
+                },
+                index,
+                request: raw,
+            })
+            .collect();
+        (request, partitions)
+    }
+}
+
+/// A partition of a started `PartitionedSend` that has not been marked as ready
+///
+/// Dereferences to its part of the send buffer.  The partition is marked as ready when it is
+/// dropped or passed to [`ready()`](#method.ready) or [`ready_all()`](#method.ready_all).
+///
+/// # Standard section(s)
+///
+/// 4.2.3
+pub struct SendPartition<'b, T> {
+    data: &'b mut [T],
+    index: c_int,
+    request: MPI_Request,
+}
+
+// The request handle is only used to mark the partition as ready.
+unsafe impl<'b, T: Send> Send for SendPartition<'b, T> {}
+
+impl<'b, T> fmt::Debug for SendPartition<'b, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("SendPartition")
+            .field("index", &self.index)
+            .field("request", &self.request)
+            .finish()
+    }
+}
+
+impl<'b, T> Deref for SendPartition<'b, T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        self.data
+    }
+}
+
+impl<'b, T> DerefMut for SendPartition<'b, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.data
+    }
+}
+
+impl<'b, T> Drop for SendPartition<'b, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Pready(self.index, self.request);
+        }
+    }
+}
+
+impl<'b, T> SendPartition<'b, T> {
+    /// The index of the partition
+    pub fn index(&self) -> Count {
+        self.index
+    }
+
+    /// Mark the partition as ready to be sent.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.3
+    pub fn ready(self) {}
+
+    /// Mark several partitions of the same operation as ready to be sent.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.3
+    pub fn ready_all<I>(partitions: I)
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let partitions: Vec<_> = partitions.into_iter().collect();
+        let request = match partitions.first() {
+            Some(partition) => partition.request,
+            None => return,
+        };
+        assert!(
+            partitions.iter().all(|p| p.request == request),
+            "partitions of different operations cannot be marked as ready together"
+        );
+        let indices: IntArray = partitions.iter().map(|p| p.index).collect();
+        let first = indices[0];
+        let last = indices[indices.len() - 1];
+        let contiguous = indices.windows(2).all(|w| w[1] == w[0] + 1);
+        unsafe {
+            if contiguous {
+                ffi::MPI_Pready_range(first, last, request);
+            } else {
+                let len = indices.len().value_as().expect("too many partitions");
+                ffi::MPI_Pready_list(len, indices.as_ptr(), request);
+            }
+        }
+        for partition in partitions {
+            mem::forget(partition);
+        }
+    }
+}
+
+/// A persistent partitioned receive request registered with a `Scope` of lifetime `'a`
+///
+/// The receive buffer is borrowed for the lifetime of the request.  Each call to
+/// [`start()`](#method.start) returns a `Request` for the completion of the whole operation, along
+/// with `ReceivePartitions` to access partitions that have already arrived.
+///
+/// # Examples
+///
+/// See `examples/partitioned.rs`
+///
+/// # Standard section(s)
+///
+/// 4.2.2
+pub struct PartitionedReceive<'a, T, S: Scope<'a> = StaticScope> {
+    request: PersistentRequest<'a, (), S>,
+    buf: *mut T,
+    partition_len: usize,
+    partitions: Count,
+    phantom: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T, S: Scope<'a>> fmt::Debug for PartitionedReceive<'a, T, S> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("PartitionedReceive")
+            .field("request", &self.request.as_raw())
+            .field("partitions", &self.partitions)
+            .finish()
+    }
+}
+
+unsafe impl<'a, T, S: Scope<'a>> AsRaw for PartitionedReceive<'a, T, S> {
+    type Raw = MPI_Request;
+    fn as_raw(&self) -> Self::Raw {
+        self.request.as_raw()
+    }
+}
+
+impl<'a, T, S: Scope<'a>> PartitionedReceive<'a, T, S> {
+    /// Construct a partitioned receive request object from the raw MPI type.
+    ///
+    /// # Safety
+    /// - `request` must be a live, inactive, partitioned receive request on `buf` with
+    ///   `partitions` partitions.
+    /// - `request` must not be used after calling `from_raw`.
+    pub unsafe fn from_raw(
+        request: MPI_Request,
+        buf: &'a mut [T],
+        partitions: Count,
+        scope: S,
+    ) -> Self {
+        PartitionedReceive {
+            partition_len: partition_len(buf.len(), partitions),
+            request: PersistentRequest::from_raw(request, &(), scope),
+            buf: buf.as_mut_ptr(),
+            partitions,
+            phantom: PhantomData,
+        }
+    }
+
+    /// The number of partitions
+    pub fn num_partitions(&self) -> Count {
+        self.partitions
+    }
+
+    /// Start the operation.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/partitioned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9, 4.2.2
+    pub fn start(
+        &mut self,
+    ) -> (
+        Request<'_, (), PersistentScope<'_>>,
+        ReceivePartitions<'_, T>,
+    ) {
+        let request = self.request.start();
+        let partitions = ReceivePartitions {
+            buf: self.buf,
+            partition_len: self.partition_len,
+            partitions: self.partitions,
+            request: request.as_raw(),
+            phantom: PhantomData,
+        };
+        (request, partitions)
+    }
+}
+
+/// The partitions of a started `PartitionedReceive`
+///
+/// # Standard section(s)
+///
+/// 4.2.4
+pub struct ReceivePartitions<'b, T> {
+    buf: *const T,
+    partition_len: usize,
+    partitions: Count,
+    request: MPI_Request,
+    phantom: PhantomData<&'b [T]>,
+}
+
+// Partitions are only read once MPI has stopped writing to them.
+unsafe impl<'b, T: Sync> Send for ReceivePartitions<'b, T> {}
+unsafe impl<'b, T: Sync> Sync for ReceivePartitions<'b, T> {}
+
+impl<'b, T> fmt::Debug for ReceivePartitions<'b, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("ReceivePartitions")
+            .field("request", &self.request)
+            .field("partitions", &self.partitions)
+            .finish()
+    }
+}
+
+impl<'b, T> ReceivePartitions<'b, T> {
+    /// The number of partitions
+    pub fn num_partitions(&self) -> Count {
+        self.partitions
+    }
+
+    /// Check whether partition `partition` has arrived.
+    ///
+    /// Returns the data of the partition if it has arrived.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.4
+    pub fn arrived(&self, partition: Count) -> Option<&[T]> {
+        assert!(
+            (0..self.partitions).contains(&partition),
+            "partition index out of range"
+        );
+        let mut flag: c_int = 0;
+        unsafe {
+            ffi::MPI_Parrived(self.request, partition, &mut flag);
+        }
+        if flag != 0 {
+            let i: usize = partition.value_as().expect("partition index out of range");
+            Some(unsafe {
+                slice::from_raw_parts(self.buf.add(i * self.partition_len), self.partition_len)
+            })
+        } else {
+            None
+        }
+    }
+}
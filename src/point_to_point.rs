@@ -7,27 +7,33 @@
 //! # Unfinished features
 //!
 //! - **3.2.6**: `MPI_STATUS_IGNORE`
-//! - **3.6**: Buffer usage, `MPI_Buffer_attach()`, `MPI_Buffer_detach()`
-//! - **3.9**: Persistent requests, `MPI_Send_init()`, `MPI_Bsend_init()`, `MPI_Ssend_init()`,
-//! `MPI_Rsend_init()`, `MPI_Recv_init()`, `MPI_Start()`, `MPI_Startall()`
 
 use std::alloc::{self, Layout};
-use std::mem::{transmute, MaybeUninit};
+use std::mem::MaybeUninit;
+use std::os::raw::c_int;
 use std::{fmt, ptr};
 
 use conv::ConvUtil;
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
 
-use super::{Count, Tag};
+use super::{Count, MpiError, Tag};
 
 use crate::ffi;
 use crate::ffi::{MPI_Message, MPI_Status};
 
 use crate::datatype::traits::*;
+use crate::datatype::{assume_init_prefix, assume_init_vec, uninit_vec};
+use crate::error_handling::with_errors_returned;
+#[cfg(mpi4)]
+use crate::partitioned::{self, PartitionedReceive, PartitionedSend};
 use crate::raw::traits::*;
-use crate::request::{Request, Scope, StaticScope};
+use crate::request::{OwnedRequest, PersistentRequest, Request, Scope, StaticScope};
+#[cfg(feature = "serde")]
+use crate::serialization::WireFormat;
 use crate::topology::traits::*;
 use crate::topology::{AnyProcess, CommunicatorRelation, Process, Rank};
-use crate::{with_uninitialized, with_uninitialized2};
+use crate::{check, with_uninitialized, with_uninitialized2, Error};
 
 // TODO: rein in _with_tag ugliness, use optional tags or make tag part of Source and Destination
 
@@ -236,6 +242,138 @@ pub unsafe trait Source: AsCommunicator {
         self.receive_into_with_tag(buf, unsafe { ffi::RSMPI_ANY_TAG })
     }
 
+    /// Receive a message into a `Buffer` and report errors.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` into `Buffer` `buf`.
+    ///
+    /// Returns an error if the receive fails and the error handler of the communicator returns
+    /// errors.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handling.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4, 8.3
+    fn try_receive_into_with_tag<Buf: ?Sized>(
+        &self,
+        buf: &mut Buf,
+        tag: Tag,
+    ) -> Result<Status, MpiError>
+    where
+        Buf: BufferMut,
+    {
+        let mut status = MaybeUninit::uninit();
+        let code = unsafe {
+            ffi::MPI_Recv(
+                buf.pointer_mut(),
+                buf.count(),
+                buf.as_datatype().as_raw(),
+                self.source_rank(),
+                tag,
+                self.as_communicator().as_raw(),
+                status.as_mut_ptr(),
+            )
+        };
+        check("MPI_Recv", code)?;
+        Ok(Status(unsafe { status.assume_init() }))
+    }
+
+    /// Receive a message into a `Buffer` and report errors.
+    ///
+    /// Receive a message from `Source` `&self` into `Buffer` `buf`.
+    ///
+    /// See `try_receive_into_with_tag`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4, 8.3
+    fn try_receive_into<Buf: ?Sized>(&self, buf: &mut Buf) -> Result<Status, MpiError>
+    where
+        Buf: BufferMut,
+    {
+        self.try_receive_into_with_tag(buf, unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
+    /// Receive a message into uninitialized memory.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` into `buf`. Returns the prefix of
+    /// `buf` that has been filled in by the message.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_into_uninit_with_tag<'b, T>(
+        &self,
+        buf: &'b mut [MaybeUninit<T>],
+        tag: Tag,
+    ) -> (&'b mut [T], Status)
+    where
+        T: Equivalence,
+    {
+        let status = self.receive_into_with_tag(buf, tag);
+        let len = received_len::<T>(&status);
+        (unsafe { assume_init_prefix(buf, len) }, status)
+    }
+
+    /// Receive a message into uninitialized memory.
+    ///
+    /// Receive a message from `Source` `&self` into `buf`. Returns the prefix of `buf` that has
+    /// been filled in by the message.
+    ///
+    /// # Examples
+    /// See `examples/receive_uninit.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_into_uninit<'b, T>(&self, buf: &'b mut [MaybeUninit<T>]) -> (&'b mut [T], Status)
+    where
+        T: Equivalence,
+    {
+        self.receive_into_uninit_with_tag(buf, unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
+    /// Receive a message into the spare capacity of a `Vec`.
+    ///
+    /// Receive a message from `Source` `&self` tagged `tag` and append its contents to `vec`. At
+    /// most `vec.capacity() - vec.len()` elements can be received.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_into_spare_capacity_with_tag<T>(&self, vec: &mut Vec<T>, tag: Tag) -> Status
+    where
+        T: Equivalence,
+    {
+        let (received, status) = self.receive_into_uninit_with_tag(vec.spare_capacity_mut(), tag);
+        let received = received.len();
+        let len = vec.len() + received;
+        unsafe {
+            vec.set_len(len);
+        }
+        status
+    }
+
+    /// Receive a message into the spare capacity of a `Vec`.
+    ///
+    /// Receive a message from `Source` `&self` and append its contents to `vec`. At most
+    /// `vec.capacity() - vec.len()` elements can be received.
+    ///
+    /// # Examples
+    /// See `examples/receive_uninit.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.4
+    fn receive_into_spare_capacity<T>(&self, vec: &mut Vec<T>) -> Status
+    where
+        T: Equivalence,
+    {
+        self.receive_into_spare_capacity_with_tag(vec, unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
     /// Receive a message containing multiple instances of type `Msg` into a `Vec`.
     ///
     /// Receive a message from `Source` `&self` tagged `tag` containing multiple instances of type
@@ -306,6 +444,31 @@ pub unsafe trait Source: AsCommunicator {
         }
     }
 
+    /// Initiate an immediate (non-blocking) receive operation.
+    ///
+    /// Initiate receiving a message matching `tag` into `buf`.
+    ///
+    /// Unlike `immediate_receive_into_with_tag`, the buffer is moved into the returned
+    /// `OwnedRequest` and handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_receive_into_with_tag_owned<Buf>(&self, buf: Buf, tag: Tag) -> OwnedRequest<Buf>
+    where
+        Buf: 'static + BufferMut,
+    {
+        unsafe {
+            OwnedRequest::start_cancellable(buf, |buf| {
+                self.immediate_receive_into_with_tag(StaticScope, buf, tag)
+            })
+        }
+    }
+
     /// Initiate an immediate (non-blocking) receive operation.
     ///
     /// Initiate receiving a message into `buf`.
@@ -328,6 +491,168 @@ pub unsafe trait Source: AsCommunicator {
         self.immediate_receive_into_with_tag(scope, buf, unsafe { ffi::RSMPI_ANY_TAG })
     }
 
+    /// Initiate an immediate (non-blocking) receive operation.
+    ///
+    /// Initiate receiving a message into `buf`.
+    ///
+    /// Unlike `immediate_receive_into`, the buffer is moved into the returned `OwnedRequest` and
+    /// handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_receive_into_owned<Buf>(&self, buf: Buf) -> OwnedRequest<Buf>
+    where
+        Buf: 'static + BufferMut,
+    {
+        unsafe {
+            OwnedRequest::start_cancellable(buf, |buf| {
+                self.immediate_receive_into(StaticScope, buf)
+            })
+        }
+    }
+
+    /// Create a persistent receive request.
+    ///
+    /// Bind receiving a message matching `tag` into `buf` to a request that can be started
+    /// repeatedly.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    fn receive_init_with_tag<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+        tag: Tag,
+    ) -> PersistentRequest<'a, Buf, Sc>
+    where
+        Buf: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            PersistentRequest::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Recv_init(
+                        buf.pointer_mut(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        self.source_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                scope,
+            )
+        }
+    }
+
+    /// Create a persistent receive request.
+    ///
+    /// Bind receiving a message into `buf` to a request that can be started repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    fn receive_init<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a mut Buf,
+    ) -> PersistentRequest<'a, Buf, Sc>
+    where
+        Buf: 'a + BufferMut,
+        Sc: Scope<'a>,
+    {
+        self.receive_init_with_tag(scope, buf, unsafe { ffi::RSMPI_ANY_TAG })
+    }
+
+    /// Create a persistent partitioned receive request.
+    ///
+    /// Bind receiving a message matching `tag` into `buf`, split into `partitions` partitions of
+    /// equal size, to a request that can be started repeatedly.  Neither the source nor `tag` may
+    /// be a wildcard.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `buf` is not a multiple of `partitions`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2
+    #[cfg(mpi4)]
+    fn partitioned_receive_init_with_tag<'a, Sc, T>(
+        &self,
+        scope: Sc,
+        buf: &'a mut [T],
+        partitions: Count,
+        tag: Tag,
+    ) -> PartitionedReceive<'a, T, Sc>
+    where
+        T: 'a + Equivalence,
+        Sc: Scope<'a>,
+    {
+        let count: ffi::MPI_Count = partitioned::partition_len(buf.len(), partitions)
+            .value_as()
+            .expect("partition too large");
+        unsafe {
+            let (_, request) = with_uninitialized(|request| {
+                ffi::MPI_Precv_init(
+                    buf.pointer_mut(),
+                    partitions,
+                    count,
+                    T::equivalent_datatype().as_raw(),
+                    self.source_rank(),
+                    tag,
+                    self.as_communicator().as_raw(),
+                    ffi::RSMPI_INFO_NULL,
+                    request,
+                )
+            });
+            PartitionedReceive::from_raw(request, buf, partitions, scope)
+        }
+    }
+
+    /// Create a persistent partitioned receive request.
+    ///
+    /// Bind receiving a message with the default tag into `buf`, split into `partitions`
+    /// partitions of equal size, to a request that can be started repeatedly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `buf` is not a multiple of `partitions`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/partitioned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2
+    #[cfg(mpi4)]
+    fn partitioned_receive_init<'a, Sc, T>(
+        &self,
+        scope: Sc,
+        buf: &'a mut [T],
+        partitions: Count,
+    ) -> PartitionedReceive<'a, T, Sc>
+    where
+        T: 'a + Equivalence,
+        Sc: Scope<'a>,
+    {
+        self.partitioned_receive_init_with_tag(scope, buf, partitions, Tag::default())
+    }
+
     /// Initiate a non-blocking receive operation for messages matching tag `tag`.
     ///
     /// # Standard section(s)
@@ -463,6 +788,50 @@ pub unsafe trait Source: AsCommunicator {
     fn immediate_matched_probe(&self) -> Option<(Message, Status)> {
         self.immediate_matched_probe_with_tag(unsafe { ffi::RSMPI_ANY_TAG })
     }
+
+    /// Receive a value encoded in `format` from a message matching `tag`.
+    ///
+    /// The message is matched by `matched_probe_with_tag()` and its size determined from the
+    /// `Status`.  The message must have been sent by `send_serialized()`.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.2, 3.8.3
+    #[cfg(feature = "serde")]
+    fn receive_deserialized_with_tag<F, T>(
+        &self,
+        format: &F,
+        tag: Tag,
+    ) -> Result<(T, Status), F::Error>
+    where
+        F: WireFormat,
+        T: DeserializeOwned,
+    {
+        let (bytes, status) = self.matched_probe_with_tag(tag).matched_receive_vec::<u8>();
+        Ok((format.deserialize(&bytes)?, status))
+    }
+
+    /// Receive a value encoded in `format`.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/serialized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.2, 3.8.3
+    #[cfg(feature = "serde")]
+    fn receive_deserialized<F, T>(&self, format: &F) -> Result<(T, Status), F::Error>
+    where
+        F: WireFormat,
+        T: DeserializeOwned,
+    {
+        self.receive_deserialized_with_tag(format, unsafe { ffi::RSMPI_ANY_TAG })
+    }
 }
 
 unsafe impl<'a> Source for AnyProcess<'a> {
@@ -540,58 +909,66 @@ pub trait Destination: AsCommunicator {
         self.send_with_tag(buf, Tag::default())
     }
 
-    /// Blocking buffered mode send operation
+    /// Blocking standard mode send operation that reports errors
     ///
     /// Send the contents of a `Buffer` to the `Destination` `&self` and tag it.
     ///
+    /// Returns an error if the send fails and the error handler of the communicator returns
+    /// errors.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handling.rs`
+    ///
     /// # Standard section(s)
     ///
-    /// 3.4
-    fn buffered_send_with_tag<Buf: ?Sized>(&self, buf: &Buf, tag: Tag)
+    /// 3.2.1, 8.3
+    fn try_send_with_tag<Buf: ?Sized>(&self, buf: &Buf, tag: Tag) -> Result<(), MpiError>
     where
         Buf: Buffer,
     {
-        unsafe {
-            ffi::MPI_Bsend(
+        let code = unsafe {
+            ffi::MPI_Send(
                 buf.pointer(),
                 buf.count(),
                 buf.as_datatype().as_raw(),
                 self.destination_rank(),
                 tag,
                 self.as_communicator().as_raw(),
-            );
-        }
+            )
+        };
+        check("MPI_Send", code)
     }
 
-    /// Blocking buffered mode send operation
+    /// Blocking standard mode send operation that reports errors
     ///
     /// Send the contents of a `Buffer` to the `Destination` `&self`.
     ///
+    /// See `try_send_with_tag`.
+    ///
     /// # Standard section(s)
     ///
-    /// 3.4
-    fn buffered_send<Buf: ?Sized>(&self, buf: &Buf)
+    /// 3.2.1, 8.3
+    fn try_send<Buf: ?Sized>(&self, buf: &Buf) -> Result<(), MpiError>
     where
         Buf: Buffer,
     {
-        self.buffered_send_with_tag(buf, Tag::default())
+        self.try_send_with_tag(buf, Tag::default())
     }
 
-    /// Blocking synchronous mode send operation
+    /// Blocking buffered mode send operation
     ///
     /// Send the contents of a `Buffer` to the `Destination` `&self` and tag it.
     ///
-    /// Completes only once the matching receive operation has started.
-    ///
     /// # Standard section(s)
     ///
     /// 3.4
-    fn synchronous_send_with_tag<Buf: ?Sized>(&self, buf: &Buf, tag: Tag)
+    fn buffered_send_with_tag<Buf: ?Sized>(&self, buf: &Buf, tag: Tag)
     where
         Buf: Buffer,
     {
         unsafe {
-            ffi::MPI_Ssend(
+            ffi::MPI_Bsend(
                 buf.pointer(),
                 buf.count(),
                 buf.as_datatype().as_raw(),
@@ -602,20 +979,126 @@ pub trait Destination: AsCommunicator {
         }
     }
 
-    /// Blocking synchronous mode send operation
+    /// Blocking buffered mode send operation
     ///
     /// Send the contents of a `Buffer` to the `Destination` `&self`.
     ///
-    /// Completes only once the matching receive operation has started.
-    ///
     /// # Standard section(s)
     ///
     /// 3.4
-    fn synchronous_send<Buf: ?Sized>(&self, buf: &Buf)
+    fn buffered_send<Buf: ?Sized>(&self, buf: &Buf)
     where
         Buf: Buffer,
     {
-        self.synchronous_send_with_tag(buf, Tag::default())
+        self.buffered_send_with_tag(buf, Tag::default())
+    }
+
+    /// Blocking buffered mode send operation that reports a lack of buffer space
+    ///
+    /// Send the contents of a `Buffer` to the `Destination` `&self` and tag it.
+    ///
+    /// Unlike `buffered_send_with_tag`, this fails with `MpiError::BufferedSendOverflow` instead of
+    /// aborting the program if the message does not fit into the remaining space of the attached
+    /// buffer.  Other errors, e.g. an invalid rank or tag, are returned as `MpiError::Call`.
+    ///
+    /// Errors on the communicator are returned for the duration of the call by swapping its error
+    /// handler.  If MPI has been initialized with `Threading::Multiple`, the handler is not swapped,
+    /// since other threads may use the communicator concurrently.  Errors are then only returned if
+    /// the handler of the communicator returns them, e.g. `ErrorHandler::errors_return()`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/buffered.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.4, 3.6
+    fn try_buffered_send_with_tag<Buf: ?Sized>(&self, buf: &Buf, tag: Tag) -> Result<(), MpiError>
+    where
+        Buf: Buffer,
+    {
+        let comm = self.as_communicator();
+        let code = unsafe {
+            with_errors_returned(comm.as_raw(), || {
+                ffi::MPI_Bsend(
+                    buf.pointer(),
+                    buf.count(),
+                    buf.as_datatype().as_raw(),
+                    self.destination_rank(),
+                    tag,
+                    comm.as_raw(),
+                )
+            })
+        };
+        check("MPI_Bsend", code).map_err(|error| {
+            if error.class().and_then(|class| class.value_as().ok()) == Some(ffi::MPI_ERR_BUFFER) {
+                let size = BufferedSendPlan::new().message(comm, buf).size();
+                MpiError::BufferedSendOverflow(size)
+            } else {
+                error
+            }
+        })
+    }
+
+    /// Blocking buffered mode send operation that reports a lack of buffer space
+    ///
+    /// Send the contents of a `Buffer` to the `Destination` `&self`.
+    ///
+    /// See `try_buffered_send_with_tag`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/buffered.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.4, 3.6
+    fn try_buffered_send<Buf: ?Sized>(&self, buf: &Buf) -> Result<(), MpiError>
+    where
+        Buf: Buffer,
+    {
+        self.try_buffered_send_with_tag(buf, Tag::default())
+    }
+
+    /// Blocking synchronous mode send operation
+    ///
+    /// Send the contents of a `Buffer` to the `Destination` `&self` and tag it.
+    ///
+    /// Completes only once the matching receive operation has started.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.4
+    fn synchronous_send_with_tag<Buf: ?Sized>(&self, buf: &Buf, tag: Tag)
+    where
+        Buf: Buffer,
+    {
+        unsafe {
+            ffi::MPI_Ssend(
+                buf.pointer(),
+                buf.count(),
+                buf.as_datatype().as_raw(),
+                self.destination_rank(),
+                tag,
+                self.as_communicator().as_raw(),
+            );
+        }
+    }
+
+    /// Blocking synchronous mode send operation
+    ///
+    /// Send the contents of a `Buffer` to the `Destination` `&self`.
+    ///
+    /// Completes only once the matching receive operation has started.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.4
+    fn synchronous_send<Buf: ?Sized>(&self, buf: &Buf)
+    where
+        Buf: Buffer,
+    {
+        self.synchronous_send_with_tag(buf, Tag::default())
     }
 
     /// Blocking ready mode send operation
@@ -651,35 +1134,465 @@ pub trait Destination: AsCommunicator {
     ///
     /// # Standard section(s)
     ///
-    /// 3.4
-    fn ready_send<Buf: ?Sized>(&self, buf: &Buf)
+    /// 3.4
+    fn ready_send<Buf: ?Sized>(&self, buf: &Buf)
+    where
+        Buf: Buffer,
+    {
+        self.ready_send_with_tag(buf, Tag::default())
+    }
+
+    /// Initiate an immediate (non-blocking) standard mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in standard mode and tag it.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_send_with_tag<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a Buf,
+        tag: Tag,
+    ) -> Request<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Isend(
+                        buf.pointer(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        self.destination_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                scope,
+            )
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) standard mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in standard mode and tag it.
+    ///
+    /// Unlike `immediate_send_with_tag`, the buffer is moved into the returned `OwnedRequest` and
+    /// handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_send_with_tag_owned<Buf>(&self, buf: Buf, tag: Tag) -> OwnedRequest<Buf>
+    where
+        Buf: 'static + Buffer,
+    {
+        unsafe {
+            OwnedRequest::start_cancellable(buf, |buf| {
+                self.immediate_send_with_tag(StaticScope, &*buf, tag)
+            })
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) standard mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in standard mode.
+    ///
+    /// # Examples
+    /// See `examples/immediate.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_send<'a, Sc, Buf: ?Sized>(&self, scope: Sc, buf: &'a Buf) -> Request<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        self.immediate_send_with_tag(scope, buf, Tag::default())
+    }
+
+    /// Initiate an immediate (non-blocking) standard mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in standard mode.
+    ///
+    /// Unlike `immediate_send`, the buffer is moved into the returned `OwnedRequest` and handed
+    /// back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_send_owned<Buf>(&self, buf: Buf) -> OwnedRequest<Buf>
+    where
+        Buf: 'static + Buffer,
+    {
+        unsafe {
+            OwnedRequest::start_cancellable(buf, |buf| self.immediate_send(StaticScope, &*buf))
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) buffered mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in buffered mode and tag it.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_buffered_send_with_tag<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a Buf,
+        tag: Tag,
+    ) -> Request<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Ibsend(
+                        buf.pointer(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        self.destination_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                scope,
+            )
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) buffered mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in buffered mode and tag it.
+    ///
+    /// Unlike `immediate_buffered_send_with_tag`, the buffer is moved into the returned
+    /// `OwnedRequest` and handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_buffered_send_with_tag_owned<Buf>(&self, buf: Buf, tag: Tag) -> OwnedRequest<Buf>
+    where
+        Buf: 'static + Buffer,
+    {
+        unsafe {
+            OwnedRequest::start_cancellable(buf, |buf| {
+                self.immediate_buffered_send_with_tag(StaticScope, &*buf, tag)
+            })
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) buffered mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in buffered mode.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_buffered_send<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a Buf,
+    ) -> Request<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        self.immediate_buffered_send_with_tag(scope, buf, Tag::default())
+    }
+
+    /// Initiate an immediate (non-blocking) buffered mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in buffered mode.
+    ///
+    /// Unlike `immediate_buffered_send`, the buffer is moved into the returned `OwnedRequest` and
+    /// handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_buffered_send_owned<Buf>(&self, buf: Buf) -> OwnedRequest<Buf>
+    where
+        Buf: 'static + Buffer,
+    {
+        unsafe {
+            OwnedRequest::start_cancellable(buf, |buf| {
+                self.immediate_buffered_send(StaticScope, &*buf)
+            })
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) synchronous mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in synchronous mode and tag it.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_synchronous_send_with_tag<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a Buf,
+        tag: Tag,
+    ) -> Request<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Issend(
+                        buf.pointer(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        self.destination_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                scope,
+            )
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) synchronous mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in synchronous mode and tag it.
+    ///
+    /// Unlike `immediate_synchronous_send_with_tag`, the buffer is moved into the returned
+    /// `OwnedRequest` and handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_synchronous_send_with_tag_owned<Buf>(
+        &self,
+        buf: Buf,
+        tag: Tag,
+    ) -> OwnedRequest<Buf>
+    where
+        Buf: 'static + Buffer,
+    {
+        unsafe {
+            OwnedRequest::start_cancellable(buf, |buf| {
+                self.immediate_synchronous_send_with_tag(StaticScope, &*buf, tag)
+            })
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) synchronous mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in synchronous mode.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_synchronous_send<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a Buf,
+    ) -> Request<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        self.immediate_synchronous_send_with_tag(scope, buf, Tag::default())
+    }
+
+    /// Initiate an immediate (non-blocking) synchronous mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in synchronous mode.
+    ///
+    /// Unlike `immediate_synchronous_send`, the buffer is moved into the returned `OwnedRequest`
+    /// and handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_synchronous_send_owned<Buf>(&self, buf: Buf) -> OwnedRequest<Buf>
+    where
+        Buf: 'static + Buffer,
+    {
+        unsafe {
+            OwnedRequest::start_cancellable(buf, |buf| {
+                self.immediate_synchronous_send(StaticScope, &*buf)
+            })
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) ready mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in ready mode and tag it.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_ready_send_with_tag<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a Buf,
+        tag: Tag,
+    ) -> Request<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        unsafe {
+            Request::from_raw(
+                with_uninitialized(|request| {
+                    ffi::MPI_Irsend(
+                        buf.pointer(),
+                        buf.count(),
+                        buf.as_datatype().as_raw(),
+                        self.destination_rank(),
+                        tag,
+                        self.as_communicator().as_raw(),
+                        request,
+                    )
+                })
+                .1,
+                buf,
+                scope,
+            )
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) ready mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in ready mode and tag it.
+    ///
+    /// Unlike `immediate_ready_send_with_tag`, the buffer is moved into the returned `OwnedRequest`
+    /// and handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_ready_send_with_tag_owned<Buf>(&self, buf: Buf, tag: Tag) -> OwnedRequest<Buf>
+    where
+        Buf: 'static + Buffer,
+    {
+        unsafe {
+            OwnedRequest::start_cancellable(buf, |buf| {
+                self.immediate_ready_send_with_tag(StaticScope, &*buf, tag)
+            })
+        }
+    }
+
+    /// Initiate an immediate (non-blocking) ready mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in ready mode.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_ready_send<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a Buf,
+    ) -> Request<'a, Buf, Sc>
+    where
+        Buf: 'a + Buffer,
+        Sc: Scope<'a>,
+    {
+        self.immediate_ready_send_with_tag(scope, buf, Tag::default())
+    }
+
+    /// Initiate an immediate (non-blocking) ready mode send operation.
+    ///
+    /// Initiate sending the data in `buf` in ready mode.
+    ///
+    /// Unlike `immediate_ready_send`, the buffer is moved into the returned `OwnedRequest` and
+    /// handed back when the request is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.2
+    fn immediate_ready_send_owned<Buf>(&self, buf: Buf) -> OwnedRequest<Buf>
     where
-        Buf: Buffer,
+        Buf: 'static + Buffer,
     {
-        self.ready_send_with_tag(buf, Tag::default())
+        unsafe {
+            OwnedRequest::start_cancellable(buf, |buf| {
+                self.immediate_ready_send(StaticScope, &*buf)
+            })
+        }
     }
 
-    /// Initiate an immediate (non-blocking) standard mode send operation.
+    /// Create a persistent standard mode send request.
     ///
-    /// Initiate sending the data in `buf` in standard mode and tag it.
+    /// Bind sending the data in `buf` in standard mode and tagging it with `tag` to a request that
+    /// can be started repeatedly.
     ///
     /// # Standard section(s)
     ///
-    /// 3.7.2
-    fn immediate_send_with_tag<'a, Sc, Buf: ?Sized>(
+    /// 3.9
+    fn send_init_with_tag<'a, Sc, Buf: ?Sized>(
         &self,
         scope: Sc,
         buf: &'a Buf,
         tag: Tag,
-    ) -> Request<'a, Buf, Sc>
+    ) -> PersistentRequest<'a, Buf, Sc>
     where
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
         unsafe {
-            Request::from_raw(
+            PersistentRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Isend(
+                    ffi::MPI_Send_init(
                         buf.pointer(),
                         buf.count(),
                         buf.as_datatype().as_raw(),
@@ -696,45 +1609,51 @@ pub trait Destination: AsCommunicator {
         }
     }
 
-    /// Initiate an immediate (non-blocking) standard mode send operation.
+    /// Create a persistent standard mode send request.
     ///
-    /// Initiate sending the data in `buf` in standard mode.
+    /// Bind sending the data in `buf` in standard mode to a request that can be started repeatedly.
     ///
     /// # Examples
-    /// See `examples/immediate.rs`
+    ///
+    /// See `examples/persistent.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 3.7.2
-    fn immediate_send<'a, Sc, Buf: ?Sized>(&self, scope: Sc, buf: &'a Buf) -> Request<'a, Buf, Sc>
+    /// 3.9
+    fn send_init<'a, Sc, Buf: ?Sized>(
+        &self,
+        scope: Sc,
+        buf: &'a Buf,
+    ) -> PersistentRequest<'a, Buf, Sc>
     where
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
-        self.immediate_send_with_tag(scope, buf, Tag::default())
+        self.send_init_with_tag(scope, buf, Tag::default())
     }
 
-    /// Initiate an immediate (non-blocking) buffered mode send operation.
+    /// Create a persistent buffered mode send request.
     ///
-    /// Initiate sending the data in `buf` in buffered mode and tag it.
+    /// Bind sending the data in `buf` in buffered mode and tagging it with `tag` to a request that
+    /// can be started repeatedly.
     ///
     /// # Standard section(s)
     ///
-    /// 3.7.2
-    fn immediate_buffered_send_with_tag<'a, Sc, Buf: ?Sized>(
+    /// 3.9
+    fn buffered_send_init_with_tag<'a, Sc, Buf: ?Sized>(
         &self,
         scope: Sc,
         buf: &'a Buf,
         tag: Tag,
-    ) -> Request<'a, Buf, Sc>
+    ) -> PersistentRequest<'a, Buf, Sc>
     where
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
         unsafe {
-            Request::from_raw(
+            PersistentRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Ibsend(
+                    ffi::MPI_Bsend_init(
                         buf.pointer(),
                         buf.count(),
                         buf.as_datatype().as_raw(),
@@ -751,46 +1670,51 @@ pub trait Destination: AsCommunicator {
         }
     }
 
-    /// Initiate an immediate (non-blocking) buffered mode send operation.
+    /// Create a persistent buffered mode send request.
     ///
-    /// Initiate sending the data in `buf` in buffered mode.
+    /// Bind sending the data in `buf` in buffered mode to a request that can be started repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 3.7.2
-    fn immediate_buffered_send<'a, Sc, Buf: ?Sized>(
+    /// 3.9
+    fn buffered_send_init<'a, Sc, Buf: ?Sized>(
         &self,
         scope: Sc,
         buf: &'a Buf,
-    ) -> Request<'a, Buf, Sc>
+    ) -> PersistentRequest<'a, Buf, Sc>
     where
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
-        self.immediate_buffered_send_with_tag(scope, buf, Tag::default())
+        self.buffered_send_init_with_tag(scope, buf, Tag::default())
     }
 
-    /// Initiate an immediate (non-blocking) synchronous mode send operation.
+    /// Create a persistent synchronous mode send request.
     ///
-    /// Initiate sending the data in `buf` in synchronous mode and tag it.
+    /// Bind sending the data in `buf` in synchronous mode and tagging it with `tag` to a request that
+    /// can be started repeatedly.
     ///
     /// # Standard section(s)
     ///
-    /// 3.7.2
-    fn immediate_synchronous_send_with_tag<'a, Sc, Buf: ?Sized>(
+    /// 3.9
+    fn synchronous_send_init_with_tag<'a, Sc, Buf: ?Sized>(
         &self,
         scope: Sc,
         buf: &'a Buf,
         tag: Tag,
-    ) -> Request<'a, Buf, Sc>
+    ) -> PersistentRequest<'a, Buf, Sc>
     where
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
         unsafe {
-            Request::from_raw(
+            PersistentRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Issend(
+                    ffi::MPI_Ssend_init(
                         buf.pointer(),
                         buf.count(),
                         buf.as_datatype().as_raw(),
@@ -807,46 +1731,51 @@ pub trait Destination: AsCommunicator {
         }
     }
 
-    /// Initiate an immediate (non-blocking) synchronous mode send operation.
+    /// Create a persistent synchronous mode send request.
     ///
-    /// Initiate sending the data in `buf` in synchronous mode.
+    /// Bind sending the data in `buf` in synchronous mode to a request that can be started repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 3.7.2
-    fn immediate_synchronous_send<'a, Sc, Buf: ?Sized>(
+    /// 3.9
+    fn synchronous_send_init<'a, Sc, Buf: ?Sized>(
         &self,
         scope: Sc,
         buf: &'a Buf,
-    ) -> Request<'a, Buf, Sc>
+    ) -> PersistentRequest<'a, Buf, Sc>
     where
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
-        self.immediate_synchronous_send_with_tag(scope, buf, Tag::default())
+        self.synchronous_send_init_with_tag(scope, buf, Tag::default())
     }
 
-    /// Initiate an immediate (non-blocking) ready mode send operation.
+    /// Create a persistent ready mode send request.
     ///
-    /// Initiate sending the data in `buf` in ready mode and tag it.
+    /// Bind sending the data in `buf` in ready mode and tagging it with `tag` to a request that
+    /// can be started repeatedly.
     ///
     /// # Standard section(s)
     ///
-    /// 3.7.2
-    fn immediate_ready_send_with_tag<'a, Sc, Buf: ?Sized>(
+    /// 3.9
+    fn ready_send_init_with_tag<'a, Sc, Buf: ?Sized>(
         &self,
         scope: Sc,
         buf: &'a Buf,
         tag: Tag,
-    ) -> Request<'a, Buf, Sc>
+    ) -> PersistentRequest<'a, Buf, Sc>
     where
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
         unsafe {
-            Request::from_raw(
+            PersistentRequest::from_raw(
                 with_uninitialized(|request| {
-                    ffi::MPI_Irsend(
+                    ffi::MPI_Rsend_init(
                         buf.pointer(),
                         buf.count(),
                         buf.as_datatype().as_raw(),
@@ -863,27 +1792,140 @@ pub trait Destination: AsCommunicator {
         }
     }
 
-    /// Initiate an immediate (non-blocking) ready mode send operation.
+    /// Create a persistent ready mode send request.
     ///
-    /// Initiate sending the data in `buf` in ready mode.
+    /// Bind sending the data in `buf` in ready mode to a request that can be started repeatedly.
     ///
     /// # Examples
     ///
-    /// See `examples/immediate.rs`
+    /// See `examples/persistent.rs`
     ///
     /// # Standard section(s)
     ///
-    /// 3.7.2
-    fn immediate_ready_send<'a, Sc, Buf: ?Sized>(
+    /// 3.9
+    fn ready_send_init<'a, Sc, Buf: ?Sized>(
         &self,
         scope: Sc,
         buf: &'a Buf,
-    ) -> Request<'a, Buf, Sc>
+    ) -> PersistentRequest<'a, Buf, Sc>
     where
         Buf: 'a + Buffer,
         Sc: Scope<'a>,
     {
-        self.immediate_ready_send_with_tag(scope, buf, Tag::default())
+        self.ready_send_init_with_tag(scope, buf, Tag::default())
+    }
+
+    /// Create a persistent partitioned send request.
+    ///
+    /// Bind sending the data in `buf`, split into `partitions` partitions of equal size and tagged
+    /// with `tag`, to a request that can be started repeatedly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `buf` is not a multiple of `partitions`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2
+    #[cfg(mpi4)]
+    fn partitioned_send_init_with_tag<'a, Sc, T>(
+        &self,
+        scope: Sc,
+        buf: &'a mut [T],
+        partitions: Count,
+        tag: Tag,
+    ) -> PartitionedSend<'a, T, Sc>
+    where
+        T: 'a + Equivalence,
+        Sc: Scope<'a>,
+    {
+        let count: ffi::MPI_Count = partitioned::partition_len(buf.len(), partitions)
+            .value_as()
+            .expect("partition too large");
+        unsafe {
+            let (_, request) = with_uninitialized(|request| {
+                ffi::MPI_Psend_init(
+                    buf.pointer(),
+                    partitions,
+                    count,
+                    T::equivalent_datatype().as_raw(),
+                    self.destination_rank(),
+                    tag,
+                    self.as_communicator().as_raw(),
+                    ffi::RSMPI_INFO_NULL,
+                    request,
+                )
+            });
+            PartitionedSend::from_raw(request, buf, partitions, scope)
+        }
+    }
+
+    /// Create a persistent partitioned send request.
+    ///
+    /// Bind sending the data in `buf`, split into `partitions` partitions of equal size, to a
+    /// request that can be started repeatedly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `buf` is not a multiple of `partitions`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/partitioned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.2.2
+    #[cfg(mpi4)]
+    fn partitioned_send_init<'a, Sc, T>(
+        &self,
+        scope: Sc,
+        buf: &'a mut [T],
+        partitions: Count,
+    ) -> PartitionedSend<'a, T, Sc>
+    where
+        T: 'a + Equivalence,
+        Sc: Scope<'a>,
+    {
+        self.partitioned_send_init_with_tag(scope, buf, partitions, Tag::default())
+    }
+
+    /// Send `msg` encoded in `format` and tag it.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    #[cfg(feature = "serde")]
+    fn send_serialized_with_tag<F, T>(&self, format: &F, msg: &T, tag: Tag) -> Result<(), F::Error>
+    where
+        F: WireFormat,
+        T: Serialize + ?Sized,
+    {
+        let bytes = format.serialize(msg)?;
+        self.send_with_tag(&bytes[..], tag);
+        Ok(())
+    }
+
+    /// Send `msg` encoded in `format`.
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/serialized.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.1
+    #[cfg(feature = "serde")]
+    fn send_serialized<F, T>(&self, format: &F, msg: &T) -> Result<(), F::Error>
+    where
+        F: WireFormat,
+        T: Serialize + ?Sized,
+    {
+        self.send_serialized_with_tag(format, msg, Tag::default())
     }
 }
 
@@ -917,10 +1959,111 @@ impl Status {
         self.0.MPI_TAG
     }
 
+    /// The `Process` that sent the message, e.g. after receiving from an `AnyProcess`
+    ///
+    /// Returns `None` if the status does not name a process of `comm`, e.g. for a receive from
+    /// `MPI_PROC_NULL`.
+    pub fn source_process<'a, C>(&self, comm: &'a C) -> Option<Process<'a>>
+    where
+        C: Communicator,
+    {
+        let rank = self.source_rank();
+        if (0..comm.target_size()).contains(&rank) {
+            Some(comm.process_at_rank(rank))
+        } else {
+            None
+        }
+    }
+
     /// Number of instances of the type contained in the message
     pub fn count<D: Datatype>(&self, d: D) -> Count {
         unsafe { with_uninitialized(|count| ffi::MPI_Get_count(&self.0, d.as_raw(), count)).1 }
     }
+
+    /// Number of basic elements of type `d` contained in the message
+    ///
+    /// Unlike `count()`, this also counts the elements of partially received instances of a
+    /// derived datatype.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 4.1.11
+    pub fn elements<D: Datatype>(&self, d: D) -> Count {
+        unsafe { with_uninitialized(|count| ffi::MPI_Get_elements(&self.0, d.as_raw(), count)).1 }
+    }
+
+    /// Whether the operation described by the status has been cancelled
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.4
+    pub fn is_cancelled(&self) -> bool {
+        unsafe { with_uninitialized(|flag| ffi::MPI_Test_cancelled(&self.0, flag)).1 != 0 }
+    }
+
+    /// The error code of the operation described by the status
+    ///
+    /// Only set by functions that complete several requests at once, e.g.
+    /// `RequestCollection::wait_all()`, where it is `MPI_SUCCESS` unless the operation failed.
+    /// Failures can only be observed if MPI does not abort on errors.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.2.5
+    pub fn error(&self) -> Error {
+        self.0.MPI_ERROR
+    }
+
+    /// Set the rank of the message source
+    pub fn set_source_rank(&mut self, rank: Rank) {
+        self.0.MPI_SOURCE = rank;
+    }
+
+    /// Set the message tag
+    pub fn set_tag(&mut self, tag: Tag) {
+        self.0.MPI_TAG = tag;
+    }
+
+    /// Set the number of basic elements of type `d` contained in the message
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.3
+    pub fn set_elements<D: Datatype>(&mut self, d: D, count: Count) {
+        unsafe {
+            ffi::MPI_Status_set_elements(&mut self.0, d.as_raw(), count);
+        }
+    }
+
+    /// Mark the operation described by the status as cancelled or not
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.3
+    pub fn set_cancelled(&mut self, cancelled: bool) {
+        unsafe {
+            ffi::MPI_Status_set_cancelled(&mut self.0, cancelled.into());
+        }
+    }
+}
+
+unsafe impl AsRaw for Status {
+    type Raw = MPI_Status;
+    fn as_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+/// Number of elements of type `T` described by `status`
+fn received_len<T: Equivalence>(status: &Status) -> usize {
+    status
+        .count(T::equivalent_datatype())
+        .value_as()
+        .expect("Message element count cannot be expressed as a usize.")
 }
 
 impl fmt::Debug for Status {
@@ -1005,6 +2148,23 @@ impl Message {
         Status(status)
     }
 
+    /// Receive a previously probed message into uninitialized memory.
+    ///
+    /// Receive the message `&self` into `buf`. Returns the prefix of `buf` that has been filled in
+    /// by the message.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.3
+    pub fn matched_receive_into_uninit<T>(self, buf: &mut [MaybeUninit<T>]) -> (&mut [T], Status)
+    where
+        T: Equivalence,
+    {
+        let status = self.matched_receive_into(buf);
+        let len = received_len::<T>(&status);
+        (unsafe { assume_init_prefix(buf, len) }, status)
+    }
+
     /// Asynchronously receive a previously probed message into a `Buffer`.
     ///
     /// Asynchronously receive the message `&self` with contents matching `buf`.
@@ -1079,29 +2239,11 @@ impl MatchedReceiveVec for (Message, Status) {
         Msg: Equivalence,
     {
         let (message, status) = self;
-        let count = status
-            .count(Msg::equivalent_datatype())
-            .value_as()
-            .expect("Message element count cannot be expressed as a usize.");
-
-        #[repr(transparent)]
-        struct UninitMsg<M>(MaybeUninit<M>);
-
-        unsafe impl<M: Equivalence> Equivalence for UninitMsg<M> {
-            type Out = M::Out;
-
-            fn equivalent_datatype() -> Self::Out {
-                M::equivalent_datatype()
-            }
-        }
-
-        let mut res = (0..count)
-            .map(|_| UninitMsg::<Msg>(MaybeUninit::uninit()))
-            .collect::<Vec<_>>();
+        let mut res = uninit_vec::<Msg>(received_len::<Msg>(&status));
 
         let status = message.matched_receive_into(&mut res[..]);
 
-        let res = unsafe { transmute(res) };
+        let res = unsafe { assume_init_vec(res) };
 
         (res, status)
     }
@@ -1355,3 +2497,140 @@ where
         }
     }
 }
+
+/// Computes the size of the buffer needed for a set of buffered mode sends
+///
+/// Each message takes up as much space as its contents when packed with `MPI_Pack()`, plus
+/// `MPI_BSEND_OVERHEAD` bytes.
+///
+/// # Examples
+///
+/// See `examples/buffered.rs`
+///
+/// # Standard section(s)
+///
+/// 3.6.1
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BufferedSendPlan {
+    size: usize,
+}
+
+impl BufferedSendPlan {
+    /// A plan without any messages
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a message with the contents of `buf` sent on `comm`.
+    pub fn message<C, Buf: ?Sized>(self, comm: &C, buf: &Buf) -> Self
+    where
+        C: Communicator,
+        Buf: Buffer,
+    {
+        self.messages(comm, buf, 1)
+    }
+
+    /// Add `n` messages with the same shape as `buf` sent on `comm`.
+    ///
+    /// The size saturates at `usize::MAX`, which no buffer can be attached with.
+    pub fn messages<C, Buf: ?Sized>(self, comm: &C, buf: &Buf, n: usize) -> Self
+    where
+        C: Communicator,
+        Buf: Buffer,
+    {
+        let packed: usize = comm
+            .pack_size(buf.count(), &buf.as_datatype())
+            .value_as()
+            .expect("MPI_Pack_size returned a negative buffer size!");
+        let overhead: usize = ffi::MPI_BSEND_OVERHEAD
+            .value_as()
+            .expect("MPI_BSEND_OVERHEAD cannot be expressed as a usize.");
+        let size = packed
+            .checked_add(overhead)
+            .and_then(|message| message.checked_mul(n))
+            .and_then(|messages| messages.checked_add(self.size))
+            .unwrap_or(usize::MAX);
+        BufferedSendPlan { size }
+    }
+
+    /// The size in bytes of a buffer that can hold all planned messages at once
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// A buffer for buffered mode sends attached to a communicator
+///
+/// Buffered mode sends on the communicator use this buffer instead of the one attached with
+/// `Universe::set_buffer_size()`.  The buffer is detached when it is dropped, which waits until all
+/// messages in it have been transmitted.
+///
+/// Only available if the MPI library implements MPI 4.1.
+///
+/// # Examples
+///
+/// See `examples/buffered.rs`
+///
+/// # Standard section(s)
+///
+/// 3.6.1
+#[cfg(mpi4_1)]
+pub struct CommunicatorBuffer<'c, C: Communicator> {
+    comm: &'c C,
+    buffer: Vec<u8>,
+}
+
+#[cfg(mpi4_1)]
+impl<'c, C: Communicator> fmt::Debug for CommunicatorBuffer<'c, C> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("CommunicatorBuffer")
+            .field("size", &self.buffer.len())
+            .finish()
+    }
+}
+
+#[cfg(mpi4_1)]
+impl<'c, C: Communicator> CommunicatorBuffer<'c, C> {
+    /// Attach a buffer of `size` bytes to `comm`.
+    ///
+    /// Fails if the error handler of `comm` returns errors and the buffer cannot be attached, e.g.
+    /// because another buffer is already attached to `comm`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.6.1
+    pub fn attach(comm: &'c C, size: usize) -> Result<Self, MpiError> {
+        let mut buffer = vec![0; size];
+        let code = unsafe {
+            ffi::MPI_Comm_attach_buffer(
+                comm.as_raw(),
+                buffer.as_mut_ptr() as _,
+                buffer
+                    .len()
+                    .value_as()
+                    .expect("Buffer length exceeds the range of a C int."),
+            )
+        };
+        check("MPI_Comm_attach_buffer", code)?;
+        Ok(CommunicatorBuffer { comm, buffer })
+    }
+
+    /// The size in bytes of the buffer
+    pub fn size(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+#[cfg(mpi4_1)]
+impl<'c, C: Communicator> Drop for CommunicatorBuffer<'c, C> {
+    fn drop(&mut self) {
+        let mut addr: *const std::os::raw::c_void = ptr::null();
+        let addr_ptr: *mut *const std::os::raw::c_void = &mut addr;
+        let mut size: c_int = 0;
+        unsafe {
+            ffi::MPI_Comm_detach_buffer(self.comm.as_raw(), addr_ptr as _, &mut size);
+        }
+        assert_eq!(addr, self.buffer.as_ptr() as _);
+    }
+}
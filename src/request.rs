@@ -19,27 +19,38 @@
 //! follow the respective policy for completing the operation.  When the guard is dropped, the
 //! request will be automatically unregistered from its `Scope`.
 //!
-//! # Unfinished features
+//! Requests that own their buffers, [`OwnedRequest`](struct.OwnedRequest.html), do not need a
+//! `Scope` and can be `await`ed.  Their tasks are woken by [`progress()`](fn.progress.html).
 //!
-//! - **3.7**: Nonblocking mode:
-//!   - Completion, `MPI_Waitall()`, `MPI_Waitsome()`,
-//!   `MPI_Testany()`, `MPI_Testall()`, `MPI_Testsome()`, `MPI_Request_get_status()`
-//! - **3.8**:
-//!   - Cancellation, `MPI_Test_cancelled()`
+//! Several requests can be completed at once by [`wait_any()`](fn.wait_any.html),
+//! [`wait_all()`](fn.wait_all.html), [`wait_some()`](fn.wait_some.html) and their `test_*`
+//! counterparts, or by a [`RequestCollection`](struct.RequestCollection.html).
 
 use std::cell::Cell;
 use std::fmt;
+use std::future::Future;
 use std::marker::PhantomData;
 use std::mem::{self, MaybeUninit};
-use std::os::raw::c_int;
-use std::ptr;
+use std::os::raw::{c_int, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+use std::time::Duration;
+use std::{process, ptr};
+
+use conv::ConvUtil;
 
 use crate::ffi;
 use crate::ffi::{MPI_Request, MPI_Status};
 
+use crate::datatype::traits::*;
+use crate::environment::{check_thread, is_abort_on_panic_deferred, threading_support};
 use crate::point_to_point::Status;
 use crate::raw::traits::*;
-use crate::with_uninitialized;
+use crate::{check, with_uninitialized, MpiError, Threading};
 
 /// Check if the request is `MPI_REQUEST_NULL`.
 fn is_null(request: MPI_Request) -> bool {
@@ -56,6 +67,10 @@ fn is_null(request: MPI_Request) -> bool {
 /// Panics if the request object is dropped.  To prevent this, call `wait`, `wait_without_status`,
 /// or `test`.  Alternatively, wrap the request inside a `WaitGuard` or `CancelGuard`.
 ///
+/// The only exception is unwinding from a panic inside `environment::defer_abort_on_panic()`
+/// while `Universe::set_abort_on_panic()` is active.  The request is then waited for, so that all
+/// processes are aborted only after the outstanding operations have completed.
+///
 /// # Examples
 ///
 /// See `examples/immediate.rs`
@@ -68,6 +83,7 @@ pub struct Request<'a, D: ?Sized, S: Scope<'a> = StaticScope> {
     request: MPI_Request,
     data: &'a D,
     scope: S,
+    persistent: bool,
     phantom: PhantomData<Cell<&'a ()>>,
 }
 
@@ -87,12 +103,20 @@ where
 unsafe impl<'a, D: ?Sized, S: Scope<'a>> AsRaw for Request<'a, D, S> {
     type Raw = MPI_Request;
     fn as_raw(&self) -> Self::Raw {
+        check_thread();
         self.request
     }
 }
 
 impl<'a, D: ?Sized, S: Scope<'a>> Drop for Request<'a, D, S> {
     fn drop(&mut self) {
+        if thread::panicking() && is_abort_on_panic_deferred() {
+            unsafe {
+                ffi::MPI_Wait(&mut self.request, ffi::RSMPI_STATUS_IGNORE);
+                self.scope.unregister();
+            }
+            return;
+        }
         panic!("request was dropped without being completed");
     }
 }
@@ -128,8 +152,8 @@ pub fn wait_any<'a, D, S: Scope<'a>>(
     }
     if index != mpi_sys::MPI_UNDEFINED {
         let u_index: usize = index.try_into().expect("Error while casting i32 to usize");
-        assert!(is_null(mpi_requests[u_index]));
         let r = requests.remove(u_index);
+        assert!(r.persistent || is_null(mpi_requests[u_index]));
         unsafe {
             r.into_raw();
         }
@@ -139,6 +163,208 @@ pub fn wait_any<'a, D, S: Scope<'a>>(
     }
 }
 
+/// Wait for the completion of all requests in the vector,
+/// returns the index and status of each completed request.
+///
+/// The completed requests are removed from the vector of requests.  The error of each request is
+/// reported by `Status::error()`.  Requests that have not been completed because another request
+/// failed remain in the vector.
+///
+/// # Examples
+///
+/// See `examples/wait_any.rs`
+///
+/// # Standard section(s)
+///
+/// 3.7.5
+pub fn wait_all<'a, D: ?Sized, S: Scope<'a>>(
+    requests: &mut Vec<Request<'a, D, S>>,
+) -> Vec<(usize, Status)> {
+    let mut mpi_requests: Vec<_> = requests.iter().map(|r| r.as_raw()).collect();
+    let mut statuses = uninit_statuses(mpi_requests.len());
+    let size = request_count(&mpi_requests);
+    let code = unsafe {
+        ffi::MPI_Waitall(
+            size,
+            mpi_requests.as_mut_ptr(),
+            statuses.as_mut_ptr() as *mut MPI_Status,
+        )
+    };
+    set_status_errors(&mut statuses, code);
+    let completed = statuses
+        .into_iter()
+        .map(|status| Status::from_raw(unsafe { status.assume_init() }))
+        .enumerate()
+        .filter(|(_, status)| !is_err_pending(status.error()))
+        .collect();
+    remove_completed(requests, &mpi_requests, completed)
+}
+
+/// Wait for the completion of at least one of the requests in the vector,
+/// returns the index and status of each completed request.
+///
+/// The completed requests are removed from the vector of requests.  The error of each completed
+/// request is reported by `Status::error()`.
+///
+/// If no Request is active an empty vector is returned.
+///
+/// # Examples
+///
+/// See `examples/wait_any.rs`
+///
+/// # Standard section(s)
+///
+/// 3.7.5
+pub fn wait_some<'a, D: ?Sized, S: Scope<'a>>(
+    requests: &mut Vec<Request<'a, D, S>>,
+) -> Vec<(usize, Status)> {
+    let mut mpi_requests: Vec<_> = requests.iter().map(|r| r.as_raw()).collect();
+    let mut statuses = uninit_statuses(mpi_requests.len());
+    let mut indices: Vec<c_int> = vec![0; mpi_requests.len()];
+    let size = request_count(&mpi_requests);
+    let mut count = 0;
+    let code = unsafe {
+        ffi::MPI_Waitsome(
+            size,
+            mpi_requests.as_mut_ptr(),
+            &mut count,
+            indices.as_mut_ptr(),
+            statuses.as_mut_ptr() as *mut MPI_Status,
+        )
+    };
+    let completed = completed_some(&indices, &mut statuses, count, code);
+    remove_completed(requests, &mpi_requests, completed)
+}
+
+/// Test for the completion of all requests in the vector.
+///
+/// If all requests have been completed, they are removed from the vector and the index and
+/// status of each request is returned.  The error of each request is reported by
+/// `Status::error()`.  If some of the requests have failed, the requests that have failed or
+/// completed are removed and returned in the same way, while the pending requests remain in the
+/// vector.  Otherwise returns `None` and the requests remain in the vector.
+///
+/// # Examples
+///
+/// See `examples/wait_any.rs`
+///
+/// # Standard section(s)
+///
+/// 3.7.5
+pub fn test_all<'a, D: ?Sized, S: Scope<'a>>(
+    requests: &mut Vec<Request<'a, D, S>>,
+) -> Option<Vec<(usize, Status)>> {
+    let mut mpi_requests: Vec<_> = requests.iter().map(|r| r.as_raw()).collect();
+    let mut statuses = uninit_statuses(mpi_requests.len());
+    let size = request_count(&mpi_requests);
+    let mut flag = 0;
+    let code = unsafe {
+        ffi::MPI_Testall(
+            size,
+            mpi_requests.as_mut_ptr(),
+            &mut flag,
+            statuses.as_mut_ptr() as *mut MPI_Status,
+        )
+    };
+    if flag == 0 && !is_err_in_status(code) {
+        return None;
+    }
+    set_status_errors(&mut statuses, code);
+    let completed = statuses
+        .into_iter()
+        .map(|status| Status::from_raw(unsafe { status.assume_init() }))
+        .enumerate()
+        .filter(|(_, status)| !is_err_pending(status.error()))
+        .collect();
+    Some(remove_completed(requests, &mpi_requests, completed))
+}
+
+/// Test for the completion of some of the requests in the vector,
+/// returns the index and status of each completed request.
+///
+/// The completed requests are removed from the vector of requests.  The error of each completed
+/// request is reported by `Status::error()`.
+///
+/// # Examples
+///
+/// See `examples/wait_any.rs`
+///
+/// # Standard section(s)
+///
+/// 3.7.5
+pub fn test_some<'a, D: ?Sized, S: Scope<'a>>(
+    requests: &mut Vec<Request<'a, D, S>>,
+) -> Vec<(usize, Status)> {
+    let mut mpi_requests: Vec<_> = requests.iter().map(|r| r.as_raw()).collect();
+    let mut statuses = uninit_statuses(mpi_requests.len());
+    let mut indices: Vec<c_int> = vec![0; mpi_requests.len()];
+    let size = request_count(&mpi_requests);
+    let mut count = 0;
+    let code = unsafe {
+        ffi::MPI_Testsome(
+            size,
+            mpi_requests.as_mut_ptr(),
+            &mut count,
+            indices.as_mut_ptr(),
+            statuses.as_mut_ptr() as *mut MPI_Status,
+        )
+    };
+    let completed = completed_some(&indices, &mut statuses, count, code);
+    remove_completed(requests, &mpi_requests, completed)
+}
+
+/// A buffer of `len` statuses to be filled in by MPI
+fn uninit_statuses(len: usize) -> Vec<MaybeUninit<MPI_Status>> {
+    let mut statuses = Vec::with_capacity(len);
+    statuses.resize_with(len, MaybeUninit::uninit);
+    statuses
+}
+
+/// The number of requests as a `c_int`
+fn request_count(requests: &[MPI_Request]) -> c_int {
+    requests
+        .len()
+        .try_into()
+        .expect("Error while casting usize to i32")
+}
+
+/// The indices and statuses of the requests completed by `MPI_Waitsome()` or `MPI_Testsome()`
+fn completed_some(
+    indices: &[c_int],
+    statuses: &mut [MaybeUninit<MPI_Status>],
+    count: c_int,
+    code: c_int,
+) -> Vec<(usize, Status)> {
+    // `count` is `MPI_UNDEFINED` if there are no active requests
+    let count: usize = count.try_into().unwrap_or(0);
+    set_status_errors(&mut statuses[..count], code);
+    indices[..count]
+        .iter()
+        .zip(&statuses[..count])
+        .map(|(&index, status)| {
+            let index = index.try_into().expect("Error while casting i32 to usize");
+            (index, Status::from_raw(unsafe { status.assume_init() }))
+        })
+        .collect()
+}
+
+/// Remove the `completed` requests from `requests` and return `completed` in ascending order.
+fn remove_completed<'a, D: ?Sized, S: Scope<'a>>(
+    requests: &mut Vec<Request<'a, D, S>>,
+    mpi_requests: &[MPI_Request],
+    mut completed: Vec<(usize, Status)>,
+) -> Vec<(usize, Status)> {
+    completed.sort_by_key(|&(index, _)| index);
+    for &(index, _) in completed.iter().rev() {
+        let r = requests.remove(index);
+        assert!(r.persistent || is_null(mpi_requests[index]));
+        unsafe {
+            r.into_raw();
+        }
+    }
+    completed
+}
+
 impl<'a, D: ?Sized, S: Scope<'a>> Request<'a, D, S> {
     /// Construct a request object from the raw MPI type.
     ///
@@ -160,6 +386,7 @@ impl<'a, D: ?Sized, S: Scope<'a>> Request<'a, D, S> {
             request,
             data,
             scope,
+            persistent: false,
             phantom: Default::default(),
         }
     }
@@ -185,10 +412,12 @@ impl<'a, D: ?Sized, S: Scope<'a>> Request<'a, D, S> {
     /// The referent `MPI_Status` object is never read. Also returns the data
     /// reference.
     fn wait_with(self, status: *mut MPI_Status) -> &'a D {
+        check_thread();
         unsafe {
+            let persistent = self.persistent;
             let (mut request, data, _) = self.into_raw();
             ffi::MPI_Wait(&mut request, status);
-            assert!(is_null(request)); // persistent requests are not supported
+            assert!(persistent || is_null(request));
             data
         }
     }
@@ -215,6 +444,30 @@ impl<'a, D: ?Sized, S: Scope<'a>> Request<'a, D, S> {
         unsafe { Status::from_raw(with_uninitialized(|status| self.wait_with(status)).1) }
     }
 
+    /// Wait for an operation to finish and report errors.
+    ///
+    /// Returns an error if the operation failed and the error handler of its communicator returns
+    /// errors.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handling.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3, 8.3
+    pub fn try_wait(self) -> Result<Status, MpiError> {
+        check_thread();
+        unsafe {
+            let mut status = MaybeUninit::uninit();
+            let persistent = self.persistent;
+            let (mut request, _, _) = self.into_raw();
+            check("MPI_Wait", ffi::MPI_Wait(&mut request, status.as_mut_ptr()))?;
+            assert!(persistent || is_null(request));
+            Ok(Status::from_raw(status.assume_init()))
+        }
+    }
+
     /// Wait for an operation to finish, but don’t bother retrieving the `Status` information.
     ///
     /// Will block execution of the calling thread until the associated operation has finished.
@@ -246,7 +499,7 @@ impl<'a, D: ?Sized, S: Scope<'a>> Request<'a, D, S> {
             let (_, flag) =
                 with_uninitialized(|flag| ffi::MPI_Test(&mut request, flag, status.as_mut_ptr()));
             if flag != 0 {
-                assert!(is_null(request)); // persistent requests are not supported
+                assert!(self.persistent || is_null(request));
                 let (_, _data, _) = self.into_raw();
                 Ok(Status::from_raw(status.assume_init()))
             } else {
@@ -255,6 +508,32 @@ impl<'a, D: ?Sized, S: Scope<'a>> Request<'a, D, S> {
         }
     }
 
+    /// Check whether an operation has finished without completing the request.
+    ///
+    /// Returns the `Status` of the operation if it has finished.  Unlike `test()`, the request
+    /// remains valid and still has to be completed, e.g. by `wait()`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/wait_any.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn get_status(&self) -> Option<Status> {
+        unsafe {
+            let mut status = MaybeUninit::uninit();
+            let (_, flag) = with_uninitialized(|flag| {
+                ffi::MPI_Request_get_status(self.as_raw(), flag, status.as_mut_ptr())
+            });
+            if flag != 0 {
+                Some(Status::from_raw(status.assume_init()))
+            } else {
+                None
+            }
+        }
+    }
+
     /// Test whether an operation has finished.
     ///
     /// If the operation has finished, a tuple (`Status`, saved_data) is returned.
@@ -275,7 +554,7 @@ impl<'a, D: ?Sized, S: Scope<'a>> Request<'a, D, S> {
             let (_, flag) =
                 with_uninitialized(|flag| ffi::MPI_Test(&mut request, flag, status.as_mut_ptr()));
             if flag != 0 {
-                assert!(is_null(request)); // persistent requests are not supported
+                assert!(self.persistent || is_null(request));
                 let (_, data, _) = self.into_raw();
                 Ok((Status::from_raw(status.assume_init()), data))
             } else {
@@ -313,8 +592,11 @@ impl<'a, D: ?Sized, S: Scope<'a>> Request<'a, D, S> {
         S2: Scope<'b>,
     {
         unsafe {
+            let persistent = self.persistent;
             let (request, data, _) = self.into_raw();
-            Request::from_raw(request, data, scope)
+            let mut request = Request::from_raw(request, data, scope);
+            request.persistent = persistent;
+            request
         }
     }
 }
@@ -401,6 +683,664 @@ impl<'a, D: ?Sized, S: Scope<'a>> From<Request<'a, D, S>> for CancelGuard<'a, D,
     }
 }
 
+/// A request object for a non-blocking operation that owns its buffers
+///
+/// The buffers `B` (e.g. a `Vec<T>`, a `Box<[T]>` or a tuple of a send and a receive buffer) are
+/// moved into the request when the operation is initiated and handed back once it has completed.
+/// Since no buffers are borrowed, the request does not need a `Scope` and can be returned from
+/// functions or stored alongside other state.
+///
+/// `OwnedRequest` also implements `Future`, so the operation can be `await`ed.  The task is woken
+/// by [`progress()`](fn.progress.html), which has to be called by the application, e.g. through
+/// [`drive_progress()`](fn.drive_progress.html) on a dedicated thread.
+///
+/// If the request object is dropped before it has completed, e.g. because a future that awaits it
+/// is cancelled by `select!` or a timeout, it is waited for before the buffers are dropped.
+/// Point-to-point operations are cancelled first, so dropping e.g. a receive that is never matched
+/// does not block.
+///
+/// # Threading
+///
+/// `OwnedRequest` is `Send` so that futures awaiting it can move between the threads of an
+/// executor.  Completing or dropping it on a thread other than the one that started it is only
+/// allowed if MPI was initialized with at least `Threading::Serialized`, which is checked when
+/// thread checks are enabled through `Universe::set_thread_checks`.
+///
+/// # Examples
+///
+/// See `examples/immediate_owned.rs` and `examples/immediate_future.rs`
+///
+/// # Standard section(s)
+///
+/// 3.7.1
+#[must_use]
+pub struct OwnedRequest<B> {
+    request: MPI_Request,
+    buffers: *mut B,
+    cancel_on_drop: bool,
+}
+
+// The request only hands out the buffers it owns.  Completing it on another thread than the one
+// that started it requires a threading level of at least `Threading::Serialized`, see the type
+// documentation.
+unsafe impl<B: Send> Send for OwnedRequest<B> {}
+
+impl<B> fmt::Debug for OwnedRequest<B> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("OwnedRequest")
+            .field("request", &self.request)
+            .finish()
+    }
+}
+
+unsafe impl<B> AsRaw for OwnedRequest<B> {
+    type Raw = MPI_Request;
+    fn as_raw(&self) -> Self::Raw {
+        check_thread();
+        self.request
+    }
+}
+
+impl<B> Drop for OwnedRequest<B> {
+    fn drop(&mut self) {
+        if is_null(self.request) {
+            return;
+        }
+        check_thread();
+        unregister_pending(self.request);
+        unsafe {
+            if self.cancel_on_drop {
+                ffi::MPI_Cancel(&mut self.request);
+            }
+            ffi::MPI_Wait(&mut self.request, ffi::RSMPI_STATUS_IGNORE);
+            drop(self.take_buffers());
+        }
+    }
+}
+
+impl<B> OwnedRequest<B> {
+    /// Initiate a non-blocking operation on buffers owned by the request.
+    ///
+    /// The buffers are moved to the heap and a reference to them is passed to `start`, which
+    /// shall initiate the operation and return its request. The buffers stay in place until the
+    /// request is completed.
+    ///
+    /// # Safety
+    /// - The reference passed to `start` must only be used to initiate the operation, it does not
+    ///   actually live for `'static`.
+    /// - The request returned from `start` must not be persistent.
+    pub(crate) unsafe fn start<D: ?Sized + 'static, F>(buffers: B, start: F) -> Self
+    where
+        B: 'static,
+        F: FnOnce(&'static mut B) -> Request<'static, D>,
+    {
+        let buffers = Box::into_raw(Box::new(buffers));
+        let (request, _, _) = start(&mut *buffers).into_raw();
+        Self::from_raw(request, buffers)
+    }
+
+    /// Initiate a non-blocking point-to-point operation on buffers owned by the request.
+    ///
+    /// Like `start`, but the operation is cancelled if the request object is dropped before it has
+    /// completed.
+    ///
+    /// # Safety
+    /// See `start`.
+    pub(crate) unsafe fn start_cancellable<D: ?Sized + 'static, F>(buffers: B, start: F) -> Self
+    where
+        B: 'static,
+        F: FnOnce(&'static mut B) -> Request<'static, D>,
+    {
+        OwnedRequest {
+            cancel_on_drop: true,
+            ..Self::start(buffers, start)
+        }
+    }
+
+    /// Construct a request object from the raw MPI type and the buffers used by the operation.
+    ///
+    /// If the request object is dropped before it has completed, it is waited for without being
+    /// cancelled.
+    ///
+    /// # Safety
+    /// - `request` must be a live, non-persistent MPI request.
+    /// - `request` must not access any memory other than the heap allocation `buffers` points to,
+    ///   which must come from `Box::into_raw`, and memory owned by it.
+    pub unsafe fn from_raw(request: MPI_Request, buffers: *mut B) -> Self {
+        debug_assert!(!is_null(request));
+        OwnedRequest {
+            request,
+            buffers,
+            cancel_on_drop: false,
+        }
+    }
+
+    /// Deconstruct the request object into the raw MPI type and the buffers.
+    ///
+    /// # Safety
+    /// - The buffers must not be accessed or freed before the returned `MPI_Request` has been
+    ///   completed.
+    pub unsafe fn into_raw(mut self) -> (MPI_Request, *mut B) {
+        unregister_pending(self.request);
+        let request = mem::replace(&mut self.request, ffi::RSMPI_REQUEST_NULL);
+        (request, self.buffers)
+    }
+
+    /// Hand out the buffers once the request has been completed.
+    unsafe fn take_buffers(&mut self) -> B {
+        assert!(is_null(self.request));
+        *Box::from_raw(mem::replace(&mut self.buffers, ptr::null_mut()))
+    }
+
+    /// Test for completion without consuming the request object.
+    fn complete(&mut self) -> Option<(B, Status)> {
+        check_thread();
+        unsafe {
+            let mut status = MaybeUninit::uninit();
+            let (_, flag) = with_uninitialized(|flag| {
+                ffi::MPI_Test(&mut self.request, flag, status.as_mut_ptr())
+            });
+            if flag != 0 {
+                Some((self.take_buffers(), Status::from_raw(status.assume_init())))
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Wait for an operation to finish and return the buffers and the `Status`.
+    ///
+    /// Will block execution of the calling thread until the associated operation has finished.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn wait(mut self) -> (B, Status) {
+        check_thread();
+        unregister_pending(self.request);
+        unsafe {
+            let (_, status) = with_uninitialized(|status| ffi::MPI_Wait(&mut self.request, status));
+            (self.take_buffers(), Status::from_raw(status))
+        }
+    }
+
+    /// Wait for an operation to finish and return the buffers, but don’t bother retrieving the
+    /// `Status` information.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn wait_without_status(mut self) -> B {
+        check_thread();
+        unregister_pending(self.request);
+        unsafe {
+            ffi::MPI_Wait(&mut self.request, ffi::RSMPI_STATUS_IGNORE);
+            self.take_buffers()
+        }
+    }
+
+    /// Test whether an operation has finished.
+    ///
+    /// If the operation has finished, the buffers and the `Status` are returned.  Otherwise
+    /// returns the unfinished `OwnedRequest`.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/immediate_owned.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.7.3
+    pub fn test(mut self) -> Result<(B, Status), Self> {
+        let pending = unregister_pending(self.request);
+        if let Some(output) = self.complete() {
+            Ok(output)
+        } else {
+            if let Some(waker) = pending {
+                register_pending(self.request, waker);
+            }
+            Err(self)
+        }
+    }
+
+    /// Initiate cancellation of the request.
+    ///
+    /// See [`Request::cancel`](struct.Request.html#method.cancel).
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.8.4
+    pub fn cancel(&self) {
+        let _pending = lock_pending();
+        let mut request = self.request;
+        unsafe {
+            ffi::MPI_Cancel(&mut request);
+        }
+    }
+}
+
+impl<B> Future for OwnedRequest<B> {
+    type Output = (B, Status);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        assert!(!is_null(self.request), "request polled after completion");
+        unregister_pending(self.request);
+        if let Some(output) = self.complete() {
+            Poll::Ready(output)
+        } else {
+            register_pending(self.request, cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// A request awaited by a task
+struct PendingRequest {
+    request: MPI_Request,
+    waker: Waker,
+}
+
+// The request handle is only used while the lock on `PENDING` is held.
+unsafe impl Send for PendingRequest {}
+
+/// Requests of `OwnedRequest` futures that returned `Poll::Pending`
+static PENDING: Mutex<Vec<PendingRequest>> = Mutex::new(Vec::new());
+
+/// Signaled when a request is added to `PENDING`
+static PENDING_REGISTERED: Condvar = Condvar::new();
+
+/// How long `drive_progress()` sleeps without pending requests before it checks `stop` again
+const IDLE_INTERVAL: Duration = Duration::from_millis(10);
+
+fn lock_pending() -> MutexGuard<'static, Vec<PendingRequest>> {
+    PENDING.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn register_pending(request: MPI_Request, waker: Waker) {
+    lock_pending().push(PendingRequest { request, waker });
+    PENDING_REGISTERED.notify_all();
+}
+
+/// Remove `request` from the pending requests, after which `progress()` no longer accesses it.
+fn unregister_pending(request: MPI_Request) -> Option<Waker> {
+    let mut pending = lock_pending();
+    let i = pending.iter().position(|p| p.request == request)?;
+    Some(pending.swap_remove(i).waker)
+}
+
+/// Make progress on the requests awaited by `OwnedRequest` futures.
+///
+/// Checks each request of a future that is waiting for completion without completing it, and wakes
+/// the tasks of those that have finished.  Returns the number of requests that are still pending.
+///
+/// Executors do not know about MPI, so this function has to be called regularly while futures
+/// are awaited, either from the thread that runs the executor or from a dedicated thread.  The
+/// latter requires MPI to be initialized with `Threading::Multiple`.
+///
+/// # Examples
+///
+/// See `examples/immediate_future.rs`
+///
+/// # Standard section(s)
+///
+/// 3.7.3
+pub fn progress() -> usize {
+    let mut finished = vec![];
+    let remaining = {
+        let mut pending = lock_pending();
+        let mut i = 0;
+        while i < pending.len() {
+            let (_, flag) = unsafe {
+                with_uninitialized(|flag| {
+                    ffi::MPI_Request_get_status(pending[i].request, flag, ffi::RSMPI_STATUS_IGNORE)
+                })
+            };
+            if flag != 0 {
+                finished.push(pending.swap_remove(i).waker);
+            } else {
+                i += 1;
+            }
+        }
+        pending.len()
+    };
+    // Wake outside of the lock, an executor may poll the task right away.
+    for waker in finished {
+        waker.wake();
+    }
+    remaining
+}
+
+/// Drive progress on the requests awaited by `OwnedRequest` futures until `stop` is set.
+///
+/// This is an executor-agnostic progress engine meant to be run on a dedicated thread.  While no
+/// request is pending, it sleeps until a future registers one.
+///
+/// # Panics
+///
+/// Panics unless MPI has been initialized with `Threading::Multiple`.
+///
+/// # Examples
+///
+/// See `examples/immediate_future.rs`
+pub fn drive_progress(stop: &AtomicBool) {
+    assert_eq!(
+        threading_support(),
+        Threading::Multiple,
+        "drive_progress() requires MPI to be initialized with Threading::Multiple"
+    );
+    while !stop.load(Ordering::Acquire) {
+        if progress() != 0 {
+            thread::yield_now();
+            continue;
+        }
+        let pending = lock_pending();
+        if pending.is_empty() {
+            // Wake up regularly to notice `stop`
+            drop(
+                PENDING_REGISTERED
+                    .wait_timeout(pending, IDLE_INTERVAL)
+                    .unwrap_or_else(PoisonError::into_inner),
+            );
+        }
+    }
+}
+
+/// A persistent request for a point to point operation registered with a `Scope` of lifetime `'a`
+///
+/// A persistent request binds the arguments of an operation, including its buffer, once and can
+/// then be started any number of times.  Each call to [`start()`](#method.start) returns an
+/// ordinary `Request` that has to be completed before the persistent request can be started again.
+/// The started requests can be completed individually or added to a `RequestCollection`.
+///
+/// When the persistent request is dropped, any outstanding operation is waited for before the
+/// request is freed.
+///
+/// # Examples
+///
+/// See `examples/persistent.rs`
+///
+/// # Standard section(s)
+///
+/// 3.9
+pub struct PersistentRequest<'a, D: ?Sized, S: Scope<'a> = StaticScope> {
+    request: MPI_Request,
+    data: &'a D,
+    scope: S,
+    phantom: PhantomData<Cell<&'a ()>>,
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> fmt::Debug for PersistentRequest<'a, D, S>
+where
+    D: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("PersistentRequest")
+            .field("request", &self.request)
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+unsafe impl<'a, D: ?Sized, S: Scope<'a>> AsRaw for PersistentRequest<'a, D, S> {
+    type Raw = MPI_Request;
+    fn as_raw(&self) -> Self::Raw {
+        self.request
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> Drop for PersistentRequest<'a, D, S> {
+    fn drop(&mut self) {
+        unsafe {
+            // Waiting for an inactive persistent request returns immediately.
+            ffi::MPI_Wait(&mut self.request, ffi::RSMPI_STATUS_IGNORE);
+            ffi::MPI_Request_free(&mut self.request);
+            self.scope.unregister();
+        }
+    }
+}
+
+impl<'a, D: ?Sized, S: Scope<'a>> PersistentRequest<'a, D, S> {
+    /// Construct a persistent request object from the raw MPI type.
+    ///
+    /// # Safety
+    /// - `request` must be a live, inactive, persistent MPI request.
+    /// - `request` must not be used after calling `from_raw`.
+    /// - Any buffers used by `request` must live longer than `scope`.
+    pub unsafe fn from_raw(request: MPI_Request, data: &'a D, scope: S) -> Self {
+        debug_assert!(!is_null(request));
+        scope.register();
+        Self {
+            request,
+            data,
+            scope,
+            phantom: Default::default(),
+        }
+    }
+
+    /// Start the operation.
+    ///
+    /// The returned `Request` borrows the persistent request until it is completed.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/persistent.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 3.9
+    pub fn start(&mut self) -> Request<'_, D, PersistentScope<'_>> {
+        unsafe {
+            // Guard against a previously started request that was leaked without completion.
+            ffi::MPI_Wait(&mut self.request, ffi::RSMPI_STATUS_IGNORE);
+            ffi::MPI_Start(&mut self.request);
+            self.started()
+        }
+    }
+
+    /// Wrap the request in a started `Request`.
+    unsafe fn started(&mut self) -> Request<'_, D, PersistentScope<'_>> {
+        let mut request = Request::from_raw(
+            self.request,
+            self.data,
+            PersistentScope {
+                phantom: PhantomData,
+            },
+        );
+        request.persistent = true;
+        request
+    }
+}
+
+/// Start all persistent requests in `requests`.
+///
+/// Returns the started requests in the same order.
+///
+/// # Examples
+///
+/// See `examples/persistent.rs`
+///
+/// # Standard section(s)
+///
+/// 3.9
+pub fn start_all<'b, 'a: 'b, D: ?Sized, S: Scope<'a>>(
+    requests: &'b mut [PersistentRequest<'a, D, S>],
+) -> Vec<Request<'b, D, PersistentScope<'b>>> {
+    let mut raw: Vec<_> = requests.iter().map(|r| r.as_raw()).collect();
+    let count = raw
+        .len()
+        .try_into()
+        .expect("Error while casting usize to i32");
+    unsafe {
+        for request in &mut raw {
+            ffi::MPI_Wait(request, ffi::RSMPI_STATUS_IGNORE);
+        }
+        ffi::MPI_Startall(count, raw.as_mut_ptr());
+        requests.iter_mut().map(|r| r.started()).collect()
+    }
+}
+
+/// A user-defined operation that is tracked by a generalized request
+///
+/// A generalized request is started with [`start_generalized()`](fn.start_generalized.html) and
+/// can be completed with `wait()`, `test()`, `wait_any()`, a `RequestCollection`, etc. together
+/// with requests of MPI operations.  The MPI library calls the methods of this trait as callbacks
+/// from these completion functions.
+///
+/// # Examples
+///
+/// See `examples/generalized_request.rs`
+///
+/// # Standard section(s)
+///
+/// 12.2
+pub trait GeneralizedOperation {
+    /// Describe the completed operation.
+    ///
+    /// Called by a completion function after the operation has been marked as complete.  `status`
+    /// initially describes an empty message from `MPI_ANY_SOURCE` with tag `MPI_ANY_TAG` that has
+    /// not been cancelled.
+    fn query(&mut self, _status: &mut Status) {}
+
+    /// Release the resources of the operation.
+    ///
+    /// Called once after the last call to `query()`, before the operation is dropped.
+    fn free(&mut self) {}
+
+    /// Cancel the operation.
+    ///
+    /// Called by `Request::cancel()`.  `complete` tells whether the operation has already been
+    /// marked as complete.  A cancelled operation still has to be marked as complete and should
+    /// report the cancellation in `query()`.
+    fn cancel(&mut self, _complete: bool) {}
+}
+
+/// Marks the operation of a generalized request as complete
+///
+/// Dropping the completer also marks the operation as complete.  Completing the operation from a
+/// thread other than the one using the request requires `Threading::Multiple`.
+///
+/// # Standard section(s)
+///
+/// 12.2
+pub struct GeneralizedCompleter {
+    request: MPI_Request,
+}
+
+// MPI_Grequest_complete() may be called from any thread with Threading::Multiple.
+unsafe impl Send for GeneralizedCompleter {}
+
+impl fmt::Debug for GeneralizedCompleter {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("GeneralizedCompleter")
+            .field("request", &self.request)
+            .finish()
+    }
+}
+
+impl Drop for GeneralizedCompleter {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Grequest_complete(self.request);
+        }
+    }
+}
+
+impl GeneralizedCompleter {
+    /// Mark the operation as complete.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 12.2
+    pub fn complete(self) {}
+}
+
+/// Start a generalized request for a user-defined `operation`.
+///
+/// Returns a `Request` carrying `data`, which completes after the operation has been marked as
+/// complete through the returned `GeneralizedCompleter`.
+///
+/// # Examples
+///
+/// See `examples/generalized_request.rs`
+///
+/// # Standard section(s)
+///
+/// 12.2
+pub fn start_generalized<'a, D: ?Sized, S: Scope<'a>, O: GeneralizedOperation + 'a>(
+    scope: S,
+    data: &'a D,
+    operation: O,
+) -> (Request<'a, D, S>, GeneralizedCompleter) {
+    let operation = Box::into_raw(Box::new(operation));
+    unsafe {
+        let request = with_uninitialized(|request| {
+            ffi::MPI_Grequest_start(
+                Some(generalized_query::<O>),
+                Some(generalized_free::<O>),
+                Some(generalized_cancel::<O>),
+                operation.cast(),
+                request,
+            )
+        })
+        .1;
+        (
+            Request::from_raw(request, data, scope),
+            GeneralizedCompleter { request },
+        )
+    }
+}
+
+/// Run a callback of a generalized request, aborting on panic since unwinding into MPI is
+/// undefined behavior.
+fn generalized_callback<F: FnOnce()>(f: F) -> c_int {
+    if panic::catch_unwind(AssertUnwindSafe(f)).is_err() {
+        process::abort();
+    }
+    ffi::MPI_SUCCESS
+        .value_as()
+        .expect("MPI_SUCCESS cannot be expressed as a c_int")
+}
+
+unsafe extern "C" fn generalized_query<O: GeneralizedOperation>(
+    extra_state: *mut c_void,
+    status: *mut MPI_Status,
+) -> c_int {
+    generalized_callback(|| {
+        let operation = &mut *extra_state.cast::<O>();
+        let mut result = Status::from_raw(mem::zeroed());
+        result.set_source_rank(ffi::RSMPI_ANY_SOURCE);
+        result.set_tag(ffi::RSMPI_ANY_TAG);
+        result.set_elements(u8::equivalent_datatype(), 0);
+        result.set_cancelled(false);
+        operation.query(&mut result);
+        *status = result.as_raw();
+    })
+}
+
+unsafe extern "C" fn generalized_free<O: GeneralizedOperation>(extra_state: *mut c_void) -> c_int {
+    generalized_callback(|| {
+        let mut operation = Box::from_raw(extra_state.cast::<O>());
+        operation.free();
+    })
+}
+
+unsafe extern "C" fn generalized_cancel<O: GeneralizedOperation>(
+    extra_state: *mut c_void,
+    complete: c_int,
+) -> c_int {
+    generalized_callback(|| {
+        let operation = &mut *extra_state.cast::<O>();
+        operation.cancel(complete != 0);
+    })
+}
+
 /// A common interface for [`LocalScope`](struct.LocalScope.html) and
 /// [`StaticScope`](struct.StaticScope.html) used internally by the `request` module.
 ///
@@ -435,6 +1375,23 @@ unsafe impl Scope<'static> for StaticScope {
     unsafe fn unregister(&self) {}
 }
 
+/// The scope of a started persistent request
+///
+/// A started persistent request does not need any bookkeeping, since it mutably borrows its
+/// `PersistentRequest`, which waits for any outstanding operation before it is freed.
+///
+/// A `PersistentScope` can only be obtained by starting a `PersistentRequest`.
+#[derive(Debug)]
+pub struct PersistentScope<'a> {
+    phantom: PhantomData<Cell<&'a ()>>,
+}
+
+unsafe impl<'a> Scope<'a> for PersistentScope<'a> {
+    fn register(&self) {}
+
+    unsafe fn unregister(&self) {}
+}
+
 /// A temporary scope that lasts no more than the lifetime `'a`
 ///
 /// Use `LocalScope` for to perform requests with temporary buffers.
@@ -457,13 +1414,13 @@ pub struct LocalScope<'a> {
 
 #[cold]
 fn abort_on_unhandled_request() {
-    let _ = std::panic::catch_unwind(|| {
+    let _ = panic::catch_unwind(|| {
         panic!("at least one request was dropped without being completed");
     });
 
     // There's no way to tell MPI to release the buffers that were passed to it. Therefore
     // we must abort execution.
-    std::process::abort();
+    process::abort();
 }
 
 impl<'a> Drop for LocalScope<'a> {
@@ -547,6 +1504,8 @@ pub struct RequestCollection<'a, D: ?Sized> {
     requests: Vec<MPI_Request>,
     /// List of data buffers attached to each request
     data: Vec<Option<&'a D>>,
+    /// Whether each request is a started persistent request
+    persistent: Vec<bool>,
     /// Request statuses
     statuses: Vec<MaybeUninit<MPI_Status>>,
     /// Pre-allocated indices buffer for use with testsome(), waitsome(), etc.
@@ -558,15 +1517,18 @@ impl<'a, D: ?Sized> RequestCollection<'a, D> {
     fn new(reserve: usize) -> RequestCollection<'a, D> {
         let mut requests = vec![];
         let mut data = vec![];
+        let mut persistent = vec![];
         let mut statuses = vec![];
         let mut indices = vec![];
         requests.reserve(reserve);
         data.reserve(reserve);
+        persistent.reserve(reserve);
         statuses.reserve(reserve);
         indices.reserve(reserve);
         RequestCollection {
             requests,
             data,
+            persistent,
             statuses,
             indices,
         }
@@ -587,9 +1549,11 @@ impl<'a, D: ?Sized> RequestCollection<'a, D> {
         S: Scope<'a>,
     {
         let i = self.requests.len();
+        let persistent = req.persistent;
         let (req, data, _) = unsafe { req.into_raw() };
         self.requests.push(req);
         self.data.push(Some(data));
+        self.persistent.push(persistent);
         self.statuses.push(MaybeUninit::<MPI_Status>::uninit());
         self.indices.push(0);
         i
@@ -615,41 +1579,34 @@ impl<'a, D: ?Sized> RequestCollection<'a, D> {
     /// Wait for some of the requests to complete, fill result with references
     /// to the (request_index, status, saved_data) for each completed request
     /// and return the total number of completed requests.
+    ///
+    /// The error of each completed request is reported by `Status::error()`.
     pub fn wait_some(&mut self, result: &mut Vec<(usize, Status, &'a D)>) {
         result.clear();
         let mut count = 0;
-        unsafe {
+        let code = unsafe {
             let n = self.requests.len() as c_int;
-            // NOTE: not using the return value here
             ffi::MPI_Waitsome(
                 n,
                 self.requests.as_mut_ptr(),
                 &mut count,
                 self.indices.as_mut_ptr(),
                 self.statuses.as_mut_ptr() as *mut MPI_Status,
-            );
+            )
         };
 
-        let count: usize = count.try_into().expect("could not cast c_int to usize");
-        result.reserve(count);
-        for i in 0..count {
-            let idx: usize = self.indices[i]
-                .try_into()
-                .expect("could not cast c_int to usize");
-            // Persistent requests check
-            assert!(is_null(self.requests[idx]));
-            if let Some(data) = self.data[idx].take() {
-                let status = unsafe { self.statuses[idx].assume_init() };
-                let status = Status::from_raw(status);
-                result.push((idx, status, data));
-            }
-        }
+        let count: usize = count.try_into().unwrap_or(0);
+        set_status_errors(&mut self.statuses[..count], code);
+        self.collect_some(count, result);
     }
 
     /// Wait for all requests to complete, putting (request_index, status, saved_data)
     /// into result for every completed request.
+    ///
+    /// The error of each request is reported by `Status::error()`.  Requests that have not been
+    /// completed because another request failed are not put into result and remain incomplete.
     pub fn wait_all(&mut self, result: &mut Vec<(usize, Status, &'a D)>) {
-        let _res = unsafe {
+        let code = unsafe {
             ffi::MPI_Waitall(
                 self.requests
                     .len()
@@ -660,15 +1617,8 @@ impl<'a, D: ?Sized> RequestCollection<'a, D> {
             )
         };
 
-        result.clear();
-        result.reserve(self.requests.len());
-        for i in 0..self.requests.len() {
-            if let Some(data) = self.data[i].take() {
-                let status = unsafe { self.statuses[i].assume_init() };
-                let status = Status::from_raw(status);
-                result.push((i, status, data));
-            }
-        }
+        set_status_errors(&mut self.statuses, code);
+        self.collect_all(result);
     }
 
     /// Test for the completion of any requests. Returns an option containing
@@ -685,7 +1635,7 @@ impl<'a, D: ?Sized> RequestCollection<'a, D> {
 
         if flag != 0 {
             let i: usize = i.try_into().expect("could not cast c_int to usize");
-            assert!(is_null(self.requests[i]));
+            assert!(self.persistent[i] || is_null(self.requests[i]));
             self.data[i]
                 .take()
                 .map(|data| (i, Status::from_raw(status), data))
@@ -696,60 +1646,113 @@ impl<'a, D: ?Sized> RequestCollection<'a, D> {
 
     /// Test for the completion of some requests. Completed request data will be
     /// stored in the result buffer in a tuple (request_index, status, saved_data).
+    ///
+    /// The error of each completed request is reported by `Status::error()`.
     pub fn test_some(&mut self, result: &mut Vec<(usize, Status, &'a D)>) {
         result.clear();
         let n = self.requests.len() as c_int;
         let mut count = 0;
-        unsafe {
+        let code = unsafe {
             ffi::MPI_Testsome(
                 n,
                 self.requests.as_mut_ptr(),
                 &mut count,
                 self.indices.as_mut_ptr(),
                 self.statuses.as_mut_ptr() as *mut MPI_Status,
-            );
-        }
+            )
+        };
 
-        let count: usize = count.try_into().expect("could not cast c_int to usize");
-        result.reserve(count);
-        for i in 0..count {
-            let idx: usize = self.indices[i]
-                .try_into()
-                .expect("could not cast c_int to usize");
-            assert!(is_null(self.requests[idx]));
-            let status = unsafe { self.statuses[idx].assume_init() };
-            if let Some(data) = self.data[idx].take() {
-                result.push((idx, Status::from_raw(status), data));
-            }
-        }
+        let count: usize = count.try_into().unwrap_or(0);
+        set_status_errors(&mut self.statuses[..count], code);
+        self.collect_some(count, result);
     }
 
     /// Test for the completion of all requests. Saved data used by the
     /// completed requests is stored in the result buffer.
+    ///
+    /// The error of each request is reported by `Status::error()`.  Requests that have not been
+    /// completed because another request failed are not put into result and remain incomplete.
     pub fn test_all(&mut self, result: &mut Vec<(usize, Status, &'a D)>) -> bool {
         let n = self.requests.len() as c_int;
         let mut flag = 0;
-        unsafe {
+        let code = unsafe {
             ffi::MPI_Testall(
                 n,
                 self.requests.as_mut_ptr(),
                 &mut flag,
                 self.statuses.as_mut_ptr() as *mut MPI_Status,
-            );
+            )
+        };
+
+        result.clear();
+        if flag != 0 || is_err_in_status(code) {
+            set_status_errors(&mut self.statuses, code);
+            self.collect_all(result);
         }
+        flag != 0
+    }
+
+    /// Put the first `count` requests reported in `indices` and `statuses` into `result`.
+    fn collect_some(&mut self, count: usize, result: &mut Vec<(usize, Status, &'a D)>) {
+        result.reserve(count);
+        for i in 0..count {
+            let idx: usize = self.indices[i]
+                .try_into()
+                .expect("could not cast c_int to usize");
+            assert!(self.persistent[idx] || is_null(self.requests[idx]));
+            let status = unsafe { self.statuses[i].assume_init() };
+            if let Some(data) = self.data[idx].take() {
+                result.push((idx, Status::from_raw(status), data));
+            }
+        }
+    }
 
+    /// Put all requests that have been completed by a call to `MPI_Waitall()` or `MPI_Testall()`
+    /// into `result`.
+    fn collect_all(&mut self, result: &mut Vec<(usize, Status, &'a D)>) {
         result.clear();
         result.reserve(self.requests.len());
-        if flag != 0 {
-            for i in 0..self.requests.len() {
-                if let Some(data) = self.data[i].take() {
-                    let status = unsafe { self.statuses[i].assume_init() };
-                    result.push((i, Status::from_raw(status), data));
-                }
+        for i in 0..self.requests.len() {
+            let status = Status::from_raw(unsafe { self.statuses[i].assume_init() });
+            if is_err_pending(status.error()) {
+                continue;
             }
-            true
-        } else {
-            false
+            if let Some(data) = self.data[i].take() {
+                result.push((i, status, data));
+            }
+        }
+    }
+}
+
+/// Check whether `code` is `MPI_ERR_IN_STATUS`, i.e. a function completing several requests has
+/// reported the errors of the individual requests in their statuses.
+fn is_err_in_status(code: c_int) -> bool {
+    error_class(code) == Some(ffi::MPI_ERR_IN_STATUS)
+}
+
+/// Check whether `code` is `MPI_ERR_PENDING`, i.e. a request has neither failed nor completed.
+fn is_err_pending(code: c_int) -> bool {
+    error_class(code) == Some(ffi::MPI_ERR_PENDING)
+}
+
+/// The error class of `code`, which implementations may encode with additional information.
+fn error_class(code: c_int) -> Option<u32> {
+    if code.value_as::<u32>().ok() == Some(ffi::MPI_SUCCESS) {
+        return Some(ffi::MPI_SUCCESS);
+    }
+    let class = unsafe { with_uninitialized(|class| ffi::MPI_Error_class(code, class)).1 };
+    class.value_as().ok()
+}
+
+/// Fill in the error fields of `statuses` filled in by a function completing several requests,
+/// which only does so itself if it returns `MPI_ERR_IN_STATUS`.
+fn set_status_errors(statuses: &mut [MaybeUninit<MPI_Status>], code: c_int) {
+    if is_err_in_status(code) {
+        return;
+    }
+    for status in statuses {
+        unsafe {
+            ptr::addr_of_mut!((*status.as_mut_ptr()).MPI_ERROR).write(code);
         }
     }
 }
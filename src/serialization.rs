@@ -0,0 +1,121 @@
+//! Communication of values serialized with `serde`
+//!
+//! Values that implement `serde::Serialize` and `serde::Deserialize` can be sent without
+//! implementing `Equivalence` for them.  They are encoded into a byte buffer by a `WireFormat`,
+//! e.g. [`Bincode`](struct.Bincode.html) or [`Cbor`](struct.Cbor.html), which is then transferred
+//! like any other buffer of bytes.  This is meant for infrequent messages like configurations or
+//! task descriptions, bulk data should still be sent using `Equivalence` types.
+//!
+//! The operations are default methods of the `Source`, `Destination` and `Root` traits, e.g.
+//! `send_serialized()`, `receive_deserialized()`, `broadcast_serialized()` and
+//! `gather_serialized()`.
+//!
+//! This module is only available with the `serde` feature.  The `bincode` and `cbor` features
+//! enable the respective wire formats.
+
+use std::error::Error;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::Rank;
+
+/// An encoding of `serde` values into bytes
+///
+/// # Examples
+///
+/// See `examples/serialized.rs`
+pub trait WireFormat {
+    /// The error returned when a value cannot be encoded or decoded
+    type Error: Error;
+
+    /// Encode `value` into bytes.
+    fn serialize<T>(&self, value: &T) -> Result<Vec<u8>, Self::Error>
+    where
+        T: Serialize + ?Sized;
+
+    /// Decode a value from `bytes`.
+    fn deserialize<T>(&self, bytes: &[u8]) -> Result<T, Self::Error>
+    where
+        T: DeserializeOwned;
+}
+
+/// Errors of collective operations on values encoded in a `WireFormat`
+///
+/// Collective operations return an error on all processes involved if a value could not be
+/// encoded on one of them, so that no process decodes a value that was never sent.
+#[derive(thiserror::Error, Debug)]
+pub enum CollectiveError<E> {
+    /// Encoding or decoding a value failed on this process
+    #[error(transparent)]
+    Format(E),
+    /// Encoding a value failed on the process with the given rank
+    #[error("encoding a value failed on rank {0}")]
+    Remote(Rank),
+}
+
+/// The [bincode](https://docs.rs/bincode) wire format
+///
+/// Requires the `bincode` feature.
+#[cfg(feature = "bincode")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Bincode;
+
+#[cfg(feature = "bincode")]
+impl WireFormat for Bincode {
+    type Error = bincode::Error;
+
+    fn serialize<T>(&self, value: &T) -> Result<Vec<u8>, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        bincode::serialize(value)
+    }
+
+    fn deserialize<T>(&self, bytes: &[u8]) -> Result<T, Self::Error>
+    where
+        T: DeserializeOwned,
+    {
+        bincode::deserialize(bytes)
+    }
+}
+
+/// The [CBOR](https://cbor.io) wire format, implemented by [ciborium](https://docs.rs/ciborium)
+///
+/// Requires the `cbor` feature.
+#[cfg(feature = "cbor")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Cbor;
+
+/// Errors of the `Cbor` wire format
+#[cfg(feature = "cbor")]
+#[derive(thiserror::Error, Debug)]
+pub enum CborError {
+    /// Encoding a value failed
+    #[error(transparent)]
+    Serialize(#[from] ciborium::ser::Error<std::io::Error>),
+    /// Decoding a value failed
+    #[error(transparent)]
+    Deserialize(#[from] ciborium::de::Error<std::io::Error>),
+}
+
+#[cfg(feature = "cbor")]
+impl WireFormat for Cbor {
+    type Error = CborError;
+
+    fn serialize<T>(&self, value: &T) -> Result<Vec<u8>, Self::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        let mut bytes = Vec::new();
+        ciborium::into_writer(value, &mut bytes)?;
+        Ok(bytes)
+    }
+
+    fn deserialize<T>(&self, bytes: &[u8]) -> Result<T, Self::Error>
+    where
+        T: DeserializeOwned,
+    {
+        Ok(ciborium::from_reader(bytes)?)
+    }
+}
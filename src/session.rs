@@ -0,0 +1,197 @@
+//! The Sessions model
+//!
+//! Instead of initializing MPI once for the whole program with `mpi::initialize()`, independent
+//! components can each open their own `Session`.  A session exposes named process sets, e.g.
+//! `mpi://WORLD` and `mpi://SELF`.  A group of the processes of a set is turned into a
+//! communicator with `SimpleCommunicator::from_group()`.
+//!
+//! Groups and communicators do not borrow the session they are derived from, so obtaining them is
+//! `unsafe`: the caller has to drop them before the session is finalized.
+//!
+//! Sessions do not initialize the World Model, so `Universe` functionality and
+//! `#[derive(Equivalence)]` are not available unless `mpi::initialize()` is called as well.
+//!
+//! This module is only available if the MPI library implements MPI 4.0.
+//!
+//! # Examples
+//!
+//! See `examples/session.rs`
+//!
+//! # Standard section(s)
+//!
+//! 11.3
+
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::{c_char, c_int};
+
+use conv::ConvUtil;
+
+use crate::ffi;
+use crate::ffi::{MPI_Info, MPI_Session};
+use crate::info::Info;
+use crate::raw::traits::*;
+use crate::topology::UserGroup;
+use crate::{check, with_uninitialized, MpiError};
+
+/// The name of the process set containing all processes of the job
+pub const WORLD_PROCESS_SET: &str = "mpi://WORLD";
+
+/// The name of the process set containing only the current process
+pub const SELF_PROCESS_SET: &str = "mpi://SELF";
+
+/// A handle to MPI that is independent of the World Model and of other sessions
+///
+/// The session is finalized when it is dropped.
+///
+/// # Examples
+///
+/// See `examples/session.rs`
+///
+/// # Standard section(s)
+///
+/// 11.3
+pub struct Session(MPI_Session);
+
+impl Session {
+    /// Open a session.
+    ///
+    /// Errors in session functions are returned rather than aborting the program.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.1, see the `MPI_Session_init` function
+    pub fn init() -> Result<Session, MpiError> {
+        unsafe { Session::init_with_raw_info(ffi::RSMPI_INFO_NULL) }
+    }
+
+    /// Open a session, passing hints like `thread_level` in `info`.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.1, see the `MPI_Session_init` function
+    pub fn init_with_info(info: &Info) -> Result<Session, MpiError> {
+        unsafe { Session::init_with_raw_info(info.as_raw()) }
+    }
+
+    /// Open a session with the raw `info` handle.
+    ///
+    /// # Safety
+    /// `info` must be a valid info handle or `MPI_INFO_NULL`.
+    unsafe fn init_with_raw_info(info: MPI_Info) -> Result<Session, MpiError> {
+        let (code, session) = with_uninitialized(|session| {
+            ffi::MPI_Session_init(info, ffi::RSMPI_ERRORS_RETURN, session)
+        });
+        check("MPI_Session_init", code)?;
+        Ok(Session(session))
+    }
+
+    /// The hints in use by this session
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.1, see the `MPI_Session_get_info` function
+    pub fn get_info(&self) -> Info {
+        unsafe {
+            Info::from_raw(with_uninitialized(|info| ffi::MPI_Session_get_info(self.0, info)).1)
+        }
+    }
+
+    /// The names of the process sets available in this session
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.2, see the `MPI_Session_get_num_psets` and `MPI_Session_get_nth_pset` functions
+    pub fn process_sets(&self) -> Vec<String> {
+        let count = unsafe {
+            with_uninitialized(|count| {
+                ffi::MPI_Session_get_num_psets(self.0, ffi::RSMPI_INFO_NULL, count)
+            })
+            .1
+        };
+        (0..count).map(|n| self.nth_process_set(n)).collect()
+    }
+
+    /// The name of the `n`th process set
+    fn nth_process_set(&self, n: c_int) -> String {
+        unsafe {
+            // Querying with a length of 0 returns the length of the name including the terminator
+            let mut len: c_int = 0;
+            ffi::MPI_Session_get_nth_pset(
+                self.0,
+                ffi::RSMPI_INFO_NULL,
+                n,
+                &mut len,
+                [].as_mut_ptr(),
+            );
+            let mut name = vec![0u8; len.value_as().expect("Negative process set name length")];
+            ffi::MPI_Session_get_nth_pset(
+                self.0,
+                ffi::RSMPI_INFO_NULL,
+                n,
+                &mut len,
+                name.as_mut_ptr() as *mut c_char,
+            );
+            CStr::from_bytes_until_nul(&name)
+                .expect("MPI_Session_get_nth_pset returned an unterminated string")
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
+    /// Properties of the process set `name`, e.g. its `mpi_size`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.2, see the `MPI_Session_get_pset_info` function
+    pub fn process_set_info(&self, name: &str) -> Result<Info, MpiError> {
+        let name = CString::new(name)?;
+        let (code, info) = unsafe {
+            with_uninitialized(|info| ffi::MPI_Session_get_pset_info(self.0, name.as_ptr(), info))
+        };
+        check("MPI_Session_get_pset_info", code)?;
+        Ok(unsafe { Info::from_raw(info) })
+    }
+
+    /// The group of the processes in the process set `name`
+    ///
+    /// # Safety
+    /// The group does not borrow the session.  The group, and all groups, communicators and other
+    /// MPI objects derived from it, have to be dropped before the session, since using or freeing
+    /// them after the session has been finalized is erroneous.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 11.3.2, see the `MPI_Group_from_session_pset` function
+    pub unsafe fn group(&self, name: &str) -> Result<UserGroup, MpiError> {
+        let name = CString::new(name)?;
+        let (code, group) = unsafe {
+            with_uninitialized(|group| {
+                ffi::MPI_Group_from_session_pset(self.0, name.as_ptr(), group)
+            })
+        };
+        check("MPI_Group_from_session_pset", code)?;
+        Ok(UserGroup(group))
+    }
+}
+
+impl fmt::Debug for Session {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.debug_tuple("Session").field(&self.0).finish()
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Session_finalize(&mut self.0);
+        }
+    }
+}
+
+unsafe impl AsRaw for Session {
+    type Raw = MPI_Session;
+    fn as_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
@@ -13,30 +13,33 @@
 //! - **6.4**: Communicator management
 //!   - **6.4.2**: Constructors, `MPI_Comm_dup_with_info()`, `MPI_Comm_idup()`,
 //!     `MPI_Comm_split_type()`
-//!   - **6.4.4**: Info, `MPI_Comm_set_info()`, `MPI_Comm_get_info()`
 //! - **6.6**: Inter-communication
 //! - **6.7**: Caching
 //! - **6.8**: Naming objects
 //! - **7**: Process topologies
 //! - **Parts of sections**: 8, 10, 12
 use std::ffi::{CStr, CString};
-use std::mem::{ManuallyDrop, MaybeUninit, size_of};
+use std::mem::{size_of, ManuallyDrop, MaybeUninit};
 use std::os::raw::{c_char, c_int, c_void};
 use std::{process, ptr};
 
 use conv::ConvUtil;
-use mpi_sys::{MPI_Aint, MPI_Win, RSMPI_INFO_NULL};
+use mpi_sys::{MPI_Aint, MPI_Win};
 
 #[cfg(not(msmpi))]
 use crate::Tag;
-use crate::{Count, IntArray};
+#[cfg(mpi4)]
+use crate::{check, MpiError};
+use crate::{Count, Error, IntArray};
 
 use crate::attribute::CommAttribute;
 use crate::datatype::traits::*;
+use crate::error_handling::ErrorHandler;
 use crate::ffi;
-use crate::ffi::{MPI_Comm, MPI_Group};
+use crate::ffi::{MPI_Comm, MPI_Group, MPI_Info};
+use crate::info::Info;
 use crate::raw::traits::*;
-use crate::window::{AllocatedWindow, CreatedWindow};
+use crate::window::{AllocatedWindow, CreatedWindow, SliceWindow};
 use crate::with_uninitialized;
 
 mod cartesian;
@@ -126,47 +129,196 @@ impl SimpleCommunicator {
         }
     }
     #[allow(missing_docs)]
-    pub fn create_window<'a, T>(&self, size: usize, vec_ptr: &'a mut Vec<T>) -> CreatedWindow<'a, T> where T: Equivalence {
-        let mut win = CreatedWindow {
+    pub fn create_window<'a, T>(&self, size: usize, vec_ptr: &'a mut Vec<T>) -> CreatedWindow<'a, T>
+    where
+        T: Equivalence,
+    {
+        unsafe { self.create_window_with_raw_info(size, vec_ptr, ffi::RSMPI_INFO_NULL) }
+    }
+    /// Creates a window over `vec_ptr`, passing hints like `no_locks` or `accumulate_ordering` in
+    /// `info`.
+    ///
+    /// # Standard section(s)
+    /// 11.2.1, see the `MPI_Win_create` function
+    pub fn create_window_with_info<'a, T>(
+        &self,
+        size: usize,
+        vec_ptr: &'a mut Vec<T>,
+        info: &Info,
+    ) -> CreatedWindow<'a, T>
+    where
+        T: Equivalence,
+    {
+        unsafe { self.create_window_with_raw_info(size, vec_ptr, info.as_raw()) }
+    }
+    /// `info` must be a valid info handle or `MPI_INFO_NULL`.
+    unsafe fn create_window_with_raw_info<'a, T>(
+        &self,
+        size: usize,
+        vec_ptr: &'a mut Vec<T>,
+        info: MPI_Info,
+    ) -> CreatedWindow<'a, T>
+    where
+        T: Equivalence,
+    {
+        let window_handle = self.create_raw_window(vec_ptr.as_mut_ptr(), size, info);
+        CreatedWindow {
             window_vec: vec_ptr,
-            window_handle: ptr::null_mut()
-        };
+            window_handle,
+        }
+    }
+    /// Creates a window over all elements of `slice`.
+    ///
+    /// Unlike `create_window`, the memory does not have to be owned by a `Vec`, so it may be
+    /// allocated with `MPI_Alloc_mem()`, e.g. by an `MpiVec`.
+    ///
+    /// # Examples
+    /// See `examples/alloc_mem.rs`
+    ///
+    /// # Standard section(s)
+    /// 11.2.1, see the `MPI_Win_create` function
+    pub fn create_slice_window<'a, T>(&self, slice: &'a mut [T]) -> SliceWindow<'a, T>
+    where
+        T: Equivalence,
+    {
+        unsafe { self.create_slice_window_with_raw_info(slice, ffi::RSMPI_INFO_NULL) }
+    }
+    /// Creates a window over all elements of `slice`, passing hints like `no_locks` or
+    /// `accumulate_ordering` in `info`.
+    ///
+    /// # Standard section(s)
+    /// 11.2.1, see the `MPI_Win_create` function
+    pub fn create_slice_window_with_info<'a, T>(
+        &self,
+        slice: &'a mut [T],
+        info: &Info,
+    ) -> SliceWindow<'a, T>
+    where
+        T: Equivalence,
+    {
+        unsafe { self.create_slice_window_with_raw_info(slice, info.as_raw()) }
+    }
+    /// `info` must be a valid info handle or `MPI_INFO_NULL`.
+    unsafe fn create_slice_window_with_raw_info<'a, T>(
+        &self,
+        slice: &'a mut [T],
+        info: MPI_Info,
+    ) -> SliceWindow<'a, T>
+    where
+        T: Equivalence,
+    {
+        let window_handle = self.create_raw_window(slice.as_mut_ptr(), slice.len(), info);
+        SliceWindow {
+            window_slice: slice,
+            window_handle,
+        }
+    }
+    /// Creates a window over `size` elements starting at `base`.
+    ///
+    /// `info` must be a valid info handle or `MPI_INFO_NULL`.
+    unsafe fn create_raw_window<T>(&self, base: *mut T, size: usize, info: MPI_Info) -> MPI_Win
+    where
+        T: Equivalence,
+    {
+        let mut window_handle: MPI_Win = ptr::null_mut();
         unsafe {
             ffi::MPI_Win_create(
-                win.window_vec.as_mut_ptr() as *mut std::ffi::c_void,
+                base as *mut std::ffi::c_void,
                 (size * size_of::<T>()) as MPI_Aint,
                 size_of::<T>() as std::ffi::c_int,
-                RSMPI_INFO_NULL,
+                info,
                 self.as_raw(),
-                &mut win.window_handle
+                &mut window_handle,
             );
         }
-        return win;
+        window_handle
     }
     #[allow(missing_docs)]
-    pub fn allocate_window<T>(&self, size: usize) -> AllocatedWindow<T> where T: Equivalence {
+    pub fn allocate_window<T>(&self, size: usize) -> AllocatedWindow<T>
+    where
+        T: Equivalence,
+    {
+        unsafe { self.allocate_window_with_raw_info(size, ffi::RSMPI_INFO_NULL) }
+    }
+    /// Allocates a window of `size` elements, passing hints like `no_locks` or
+    /// `accumulate_ordering` in `info`.
+    ///
+    /// # Standard section(s)
+    /// 11.2.2, see the `MPI_Win_allocate` function
+    pub fn allocate_window_with_info<T>(&self, size: usize, info: &Info) -> AllocatedWindow<T>
+    where
+        T: Equivalence,
+    {
+        unsafe { self.allocate_window_with_raw_info(size, info.as_raw()) }
+    }
+    /// `info` must be a valid info handle or `MPI_INFO_NULL`.
+    unsafe fn allocate_window_with_raw_info<T>(
+        &self,
+        size: usize,
+        info: MPI_Info,
+    ) -> AllocatedWindow<T>
+    where
+        T: Equivalence,
+    {
         let mut window_base: *mut T = ptr::null_mut();
         let mut window_handle: MPI_Win = ptr::null_mut();
         unsafe {
             ffi::MPI_Win_allocate(
                 (size * size_of::<T>()) as MPI_Aint,
                 size_of::<T>() as std::ffi::c_int,
-                RSMPI_INFO_NULL,
+                info,
                 self.as_raw(),
                 &mut window_base as *mut *mut _ as *mut std::ffi::c_void,
-                &mut window_handle
+                &mut window_handle,
             );
             if window_handle.is_null() {
                 panic!("Failed to initialize window handle");
             }
             let win = AllocatedWindow {
                 window_vec: ManuallyDrop::new(Vec::from_raw_parts(window_base, size, size)),
-                window_handle: window_handle
+                window_handle: window_handle,
             };
             return win;
         }
     }
 
+    /// Creates a communicator containing the processes of `group` without a parent communicator,
+    /// e.g. from a group of a `Session`.
+    ///
+    /// All processes of `group` have to call this with the same `tag`, which distinguishes
+    /// concurrent calls.  Errors on the new communicator abort the program.
+    ///
+    /// # Safety
+    /// If `group` was derived from a `Session`, the communicator and all objects derived from it
+    /// have to be dropped before the session, see `Session::group()`.
+    ///
+    /// # Examples
+    /// See `examples/session.rs`
+    ///
+    /// # Standard section(s)
+    /// 11.3.3, see the `MPI_Comm_create_from_group` function
+    #[cfg(mpi4)]
+    pub unsafe fn from_group<G: Group + ?Sized>(
+        group: &G,
+        tag: &str,
+    ) -> Result<SimpleCommunicator, MpiError> {
+        let tag = CString::new(tag)?;
+        let (code, comm) = unsafe {
+            with_uninitialized(|comm| {
+                ffi::MPI_Comm_create_from_group(
+                    group.as_raw(),
+                    tag.as_ptr(),
+                    ffi::RSMPI_INFO_NULL,
+                    ffi::RSMPI_ERRORS_ARE_FATAL,
+                    comm,
+                )
+            })
+        };
+        check("MPI_Comm_create_from_group", code)?;
+        Ok(unsafe { SimpleCommunicator::try_from_raw(comm) }
+            .expect("MPI_Comm_create_from_group returned MPI_COMM_NULL"))
+    }
+
     /// Converts the communicator into its precise communicator type.
     ///
     /// # Standard section(s)
@@ -663,6 +815,87 @@ pub trait Communicator: sealed::AsHandle {
         }
     }
 
+    /// Set the handler that is invoked for errors raised by operations on this communicator.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handling.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.1, see the `MPI_Comm_set_errhandler` function
+    fn set_error_handler(&self, handler: &ErrorHandler) {
+        unsafe {
+            ffi::MPI_Comm_set_errhandler(self.as_raw(), handler.as_raw());
+        }
+    }
+
+    /// Get the handler that is invoked for errors raised by operations on this communicator.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handling.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.1, see the `MPI_Comm_get_errhandler` function
+    fn get_error_handler(&self) -> ErrorHandler {
+        let handle = unsafe {
+            with_uninitialized(|handle| ffi::MPI_Comm_get_errhandler(self.as_raw(), handle)).1
+        };
+        ErrorHandler::from_owned_raw(handle)
+    }
+
+    /// Set hints about the usage of this communicator.
+    ///
+    /// This is collective, all processes have to pass the same hints.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/info.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.4, see the `MPI_Comm_set_info` function
+    fn set_info(&self, info: &Info) {
+        unsafe {
+            ffi::MPI_Comm_set_info(self.as_raw(), info.as_raw());
+        }
+    }
+
+    /// Get the hints about the usage of this communicator that MPI actually uses.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/info.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 6.4.4, see the `MPI_Comm_get_info` function
+    fn get_info(&self) -> Info {
+        unsafe {
+            Info::from_raw(with_uninitialized(|info| ffi::MPI_Comm_get_info(self.as_raw(), info)).1)
+        }
+    }
+
+    /// Invoke the error handler of this communicator with the error code `code`.
+    ///
+    /// This raises errors of user-defined classes, see `error_handling::add_error_class()`, the
+    /// same way errors of MPI operations are raised.  Returns if the error handler returns.
+    ///
+    /// # Examples
+    ///
+    /// See `examples/error_handling.rs`
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.5, see the `MPI_Comm_call_errhandler` function
+    fn call_error_handler(&self, code: Error) {
+        unsafe {
+            ffi::MPI_Comm_call_errhandler(self.as_raw(), code);
+        }
+    }
+
     /// Creates a communicator with ranks laid out in a multi-dimensional space, allowing for easy
     /// neighbor-to-neighbor communication, while providing MPI with information to allow it to
     /// better optimize the physical locality of ranks that are logically close.
@@ -1112,7 +1345,7 @@ impl Group for SystemGroup {}
 /// # Standard section(s)
 ///
 /// 6.2.1
-pub struct UserGroup(MPI_Group);
+pub struct UserGroup(pub(crate) MPI_Group);
 
 impl Drop for UserGroup {
     fn drop(&mut self) {
@@ -1,3 +1,4 @@
+use crate::environment::check_thread;
 use crate::ffi;
 use crate::topology::comm_is_inter;
 use crate::traits::AsRaw;
@@ -165,6 +166,7 @@ unsafe impl AsRaw for CommunicatorHandle {
     type Raw = MPI_Comm;
 
     fn as_raw(&self) -> Self::Raw {
+        check_thread();
         match self {
             CommunicatorHandle::SelfComm => unsafe { ffi::RSMPI_COMM_SELF },
             CommunicatorHandle::World => unsafe { ffi::RSMPI_COMM_WORLD },
@@ -1,26 +1,62 @@
 #![allow(missing_docs)]
 
-use std::ffi::{c_int, c_void};
-use std::mem::{ManuallyDrop};
-use crate::{ffi, Rank};
+use crate::error_handling::ErrorHandler;
 use crate::topology::UserGroup;
 use crate::traits::{AsRaw, Equivalence};
+use crate::with_uninitialized;
+use crate::{ffi, Rank};
+use std::ffi::{c_int, c_void};
+use std::mem::ManuallyDrop;
 
-pub struct CreatedWindow<'a, T> where T: Equivalence {
+pub struct CreatedWindow<'a, T>
+where
+    T: Equivalence,
+{
     pub window_vec: &'a mut Vec<T>,
-    pub window_handle: ffi::MPI_Win
+    pub window_handle: ffi::MPI_Win,
+}
+
+/// A window over memory that is not owned by a `Vec`, e.g. an `MpiVec`
+pub struct SliceWindow<'a, T>
+where
+    T: Equivalence,
+{
+    pub window_slice: &'a mut [T],
+    pub window_handle: ffi::MPI_Win,
 }
 
-pub struct AllocatedWindow<T> where T: Equivalence {
+pub struct AllocatedWindow<T>
+where
+    T: Equivalence,
+{
     pub window_vec: ManuallyDrop<Vec<T>>,
-    pub window_handle: ffi::MPI_Win
+    pub window_handle: ffi::MPI_Win,
 }
 
-pub trait Communication<T> where T: Equivalence{
+pub trait Communication<T>
+where
+    T: Equivalence,
+{
     fn put_from_vector(&self, origin: &Vec<T>, target_rank: usize);
     fn get_from_vector(&self, origin: &mut Vec<T>, target_rank: usize);
-    fn put(&self, origin: &Vec<T>, origin_disp: usize, origin_count: usize, target_rank: usize, target_disp: usize, target_count: usize);
-    fn get(&self, origin: &mut Vec<T>, origin_disp: usize, origin_count: usize, target_rank: usize, target_disp: usize, target_count: usize);
+    fn put(
+        &self,
+        origin: &Vec<T>,
+        origin_disp: usize,
+        origin_count: usize,
+        target_rank: usize,
+        target_disp: usize,
+        target_count: usize,
+    );
+    fn get(
+        &self,
+        origin: &mut Vec<T>,
+        origin_disp: usize,
+        origin_count: usize,
+        target_rank: usize,
+        target_disp: usize,
+        target_count: usize,
+    );
     fn put_whole_vector(&self, target_rank: usize);
     fn get_whole_vector(&mut self, target_rank: usize);
 }
@@ -35,34 +71,102 @@ pub trait Synchronization {
     fn unlock(&self, rank: Rank);
 }
 
-impl<T> Communication<T> for AllocatedWindow<T> where T: Equivalence {
+impl<T> Communication<T> for AllocatedWindow<T>
+where
+    T: Equivalence,
+{
     fn put_from_vector(&self, origin: &Vec<T>, target_rank: usize) {
-        common_put(origin.as_ptr(), origin.len(), target_rank, 0, origin.len(), self.window_handle);
+        common_put(
+            origin.as_ptr(),
+            origin.len(),
+            target_rank,
+            0,
+            origin.len(),
+            self.window_handle,
+        );
     }
 
     fn get_from_vector(&self, origin: &mut Vec<T>, target_rank: usize) {
-        common_get(origin.as_mut_ptr(), origin.len(), target_rank, 0, origin.len(), self.window_handle);
+        common_get(
+            origin.as_mut_ptr(),
+            origin.len(),
+            target_rank,
+            0,
+            origin.len(),
+            self.window_handle,
+        );
     }
 
-    fn put(&self, origin: &Vec<T>, origin_disp: usize, origin_count: usize, target_rank: usize, target_disp: usize, target_count: usize) {
-        unsafe { common_put(origin.as_ptr().add(origin_disp), origin_count, target_rank, target_disp, target_count, self.window_handle); }
+    fn put(
+        &self,
+        origin: &Vec<T>,
+        origin_disp: usize,
+        origin_count: usize,
+        target_rank: usize,
+        target_disp: usize,
+        target_count: usize,
+    ) {
+        unsafe {
+            common_put(
+                origin.as_ptr().add(origin_disp),
+                origin_count,
+                target_rank,
+                target_disp,
+                target_count,
+                self.window_handle,
+            );
+        }
     }
 
-    fn get(&self, origin: &mut Vec<T>, origin_disp: usize, origin_count: usize, target_rank: usize, target_disp: usize, target_count: usize) {
-        unsafe { common_get(origin.as_mut_ptr().add(origin_disp), origin_count, target_rank, target_disp, target_count, self.window_handle); }
+    fn get(
+        &self,
+        origin: &mut Vec<T>,
+        origin_disp: usize,
+        origin_count: usize,
+        target_rank: usize,
+        target_disp: usize,
+        target_count: usize,
+    ) {
+        unsafe {
+            common_get(
+                origin.as_mut_ptr().add(origin_disp),
+                origin_count,
+                target_rank,
+                target_disp,
+                target_count,
+                self.window_handle,
+            );
+        }
     }
 
     fn put_whole_vector(&self, target_rank: usize) {
-        common_put(self.window_vec.as_ptr(), self.window_vec.len(), target_rank, 0, self.window_vec.len(), self.window_handle);
+        common_put(
+            self.window_vec.as_ptr(),
+            self.window_vec.len(),
+            target_rank,
+            0,
+            self.window_vec.len(),
+            self.window_handle,
+        );
     }
 
     fn get_whole_vector(&mut self, target_rank: usize) {
         let len = self.window_vec.len();
-        common_get(self.window_vec.as_mut_ptr(), len, target_rank, 0, len, self.window_handle);
+        common_get(
+            self.window_vec.as_mut_ptr(),
+            len,
+            target_rank,
+            0,
+            len,
+            self.window_handle,
+        );
     }
 }
 
-impl <T> Synchronization for AllocatedWindow<T> where T: Equivalence{
+impl<T> Synchronization for AllocatedWindow<T>
+where
+    T: Equivalence,
+{
     fn fence(&self) {
         common_fence(self.window_handle);
     }
@@ -93,7 +197,12 @@ impl <T> Synchronization for AllocatedWindow<T> where T: Equivalence{
 
     fn exclusive_lock(&self, rank: Rank) {
         unsafe {
-            ffi::MPI_Win_lock(ffi::MPI_LOCK_EXCLUSIVE as c_int, rank as c_int, 0, self.window_handle);
+            ffi::MPI_Win_lock(
+                ffi::MPI_LOCK_EXCLUSIVE as c_int,
+                rank as c_int,
+                0,
+                self.window_handle,
+            );
         }
     }
 
@@ -104,7 +213,16 @@ impl <T> Synchronization for AllocatedWindow<T> where T: Equivalence{
     }
 }
 
-fn common_put<T>(origin: *const T, origin_count: usize, target_rank: usize, target_disp: usize, target_count: usize, window: ffi::MPI_Win) where T: Equivalence {
+fn common_put<T>(
+    origin: *const T,
+    origin_count: usize,
+    target_rank: usize,
+    target_disp: usize,
+    target_count: usize,
+    window: ffi::MPI_Win,
+) where
+    T: Equivalence,
+{
     unsafe {
         ffi::MPI_Put(
             origin as *const c_void,
@@ -114,12 +232,21 @@ fn common_put<T>(origin: *const T, origin_count: usize, target_rank: usize, targ
             target_disp as ffi::MPI_Aint,
             target_count as c_int,
             T::equivalent_datatype().as_raw(),
-            window
+            window,
         );
     }
 }
 
-fn common_get<T>(origin: *mut T, origin_count: usize, target_rank: usize, target_disp: usize, target_count: usize, window: ffi::MPI_Win) where T: Equivalence {
+fn common_get<T>(
+    origin: *mut T,
+    origin_count: usize,
+    target_rank: usize,
+    target_disp: usize,
+    target_count: usize,
+    window: ffi::MPI_Win,
+) where
+    T: Equivalence,
+{
     unsafe {
         ffi::MPI_Get(
             origin as *mut c_void,
@@ -129,18 +256,113 @@ fn common_get<T>(origin: *mut T, origin_count: usize, target_rank: usize, target
             target_disp as ffi::MPI_Aint,
             target_count as c_int,
             T::equivalent_datatype().as_raw(),
-            window
+            window,
         );
     }
 }
 
+impl<T> AllocatedWindow<T>
+where
+    T: Equivalence,
+{
+    /// Set the handler that is invoked for errors raised by operations on this window.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.2, see the `MPI_Win_set_errhandler` function
+    pub fn set_error_handler(&self, handler: &ErrorHandler) {
+        common_set_error_handler(self.window_handle, handler);
+    }
+
+    /// Get the handler that is invoked for errors raised by operations on this window.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.2, see the `MPI_Win_get_errhandler` function
+    pub fn get_error_handler(&self) -> ErrorHandler {
+        common_get_error_handler(self.window_handle)
+    }
+}
+
+impl<'a, T> CreatedWindow<'a, T>
+where
+    T: Equivalence,
+{
+    /// Set the handler that is invoked for errors raised by operations on this window.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.2, see the `MPI_Win_set_errhandler` function
+    pub fn set_error_handler(&self, handler: &ErrorHandler) {
+        common_set_error_handler(self.window_handle, handler);
+    }
+
+    /// Get the handler that is invoked for errors raised by operations on this window.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.2, see the `MPI_Win_get_errhandler` function
+    pub fn get_error_handler(&self) -> ErrorHandler {
+        common_get_error_handler(self.window_handle)
+    }
+}
+
+impl<'a, T> SliceWindow<'a, T>
+where
+    T: Equivalence,
+{
+    /// Set the handler that is invoked for errors raised by operations on this window.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.2, see the `MPI_Win_set_errhandler` function
+    pub fn set_error_handler(&self, handler: &ErrorHandler) {
+        common_set_error_handler(self.window_handle, handler);
+    }
+
+    /// Get the handler that is invoked for errors raised by operations on this window.
+    ///
+    /// # Standard section(s)
+    ///
+    /// 8.3.2, see the `MPI_Win_get_errhandler` function
+    pub fn get_error_handler(&self) -> ErrorHandler {
+        common_get_error_handler(self.window_handle)
+    }
+}
+
+fn common_set_error_handler(window: ffi::MPI_Win, handler: &ErrorHandler) {
+    unsafe {
+        ffi::MPI_Win_set_errhandler(window, handler.as_raw());
+    }
+}
+
+fn common_get_error_handler(window: ffi::MPI_Win) -> ErrorHandler {
+    let handle =
+        unsafe { with_uninitialized(|handle| ffi::MPI_Win_get_errhandler(window, handle)).1 };
+    ErrorHandler::from_owned_raw(handle)
+}
+
 fn common_fence(window: ffi::MPI_Win) {
     unsafe {
         ffi::MPI_Win_fence(0, window);
     }
 }
 
-impl<T> Drop for AllocatedWindow<T> where T: Equivalence {
+impl<T> Drop for AllocatedWindow<T>
+where
+    T: Equivalence,
+{
+    fn drop(&mut self) {
+        unsafe {
+            ffi::MPI_Win_free(&mut self.window_handle);
+        }
+    }
+}
+
+impl<'a, T> Drop for CreatedWindow<'a, T>
+where
+    T: Equivalence,
+{
     fn drop(&mut self) {
         unsafe {
             ffi::MPI_Win_free(&mut self.window_handle);
@@ -148,10 +370,13 @@ impl<T> Drop for AllocatedWindow<T> where T: Equivalence {
     }
 }
 
-impl<'a, T> Drop for CreatedWindow<'a, T> where T: Equivalence {
+impl<'a, T> Drop for SliceWindow<'a, T>
+where
+    T: Equivalence,
+{
     fn drop(&mut self) {
         unsafe {
             ffi::MPI_Win_free(&mut self.window_handle);
         }
     }
-}
\ No newline at end of file
+}